@@ -21,9 +21,22 @@ let rec to_list = function
   | `String key -> `List (`String key :: [])
   | x -> x
 
+type elrondTree = Leaf | Node of int * elrondTree * elrondTree
+[@@deriving yojson]
+
+(* to_list only rewrites the outermost variant, so subtrees are converted here *)
+let rec tree_to_list = function
+  | `Assoc [ ("Node", `List [ x; l; r ]) ] ->
+      `List [ `String "Node"; x; tree_to_list l; tree_to_list r ]
+  | `String "Leaf" -> `List [ `String "Leaf" ]
+  | x -> x
+
+let elrondTree_to_yojson x = elrondTree_to_yojson x |> to_assoc
+let elrondTree_of_yojson x = elrondTree_of_yojson (tree_to_list x)
+
 type elrondValue =
   | L of int list
-  (* | T of int Utils.Tree.t *)
+  | T of elrondTree
   | I of int
   | B of bool
   (* | TI of (int, int) Utils.LabeledTree.t
@@ -65,17 +78,56 @@ type elrondResult =
   | Result of (elrondSpec * string) list
 [@@deriving yojson]
 
-type elrondMessage = Message of string | Result of elrondResult
+type elrondInferMode = Consistent | Weakening | Full [@@deriving yojson]
+
+(* Nested inside records and tuples, so it needs the conversion applied here
+   rather than only at the top level of a message *)
+let elrondInferMode_to_yojson x = elrondInferMode_to_yojson x |> to_assoc
+let elrondInferMode_of_yojson x = elrondInferMode_of_yojson (to_list x)
+
+let elrondInferMode_to_string = function
+  | Consistent -> "Consistent"
+  | Weakening -> "Weakening"
+  | Full -> "Full"
+
+type elrondMessage =
+  | Message of string
+  | Result of elrondInferMode * elrondResult
 [@@deriving yojson]
 
 type elrondPredicates = string list [@@deriving yojson]
 
+type arwenInput = File of { path : string } | Inline of { text : string }
+[@@deriving yojson]
+
+let arwenInput_to_yojson x = arwenInput_to_yojson x |> to_assoc
+let arwenInput_of_yojson x = arwenInput_of_yojson (to_list x)
+
+let arwenInput_to_string = function
+  | File { path } -> path
+  | Inline { text } -> text
+
+type elrondHoles = { holes : string list; trusted : string list }
+[@@deriving yojson]
+
+type inferenceOptions = {
+  bound : int;
+  max_quantified_vars : int option;
+  sample_budget : int option;
+  phase_timeout_secs : int option;
+  seed : int;
+}
+[@@deriving yojson]
+
 type arwenSetup = {
-  sourcefile : string;
-  assertionfile : string;
+  sourcefile : arwenInput;
+  assertionfile : arwenInput;
   outputdir : string;
   client_name : string;
   predicates : elrondPredicates;
+  mode : elrondInferMode;
+  holes : elrondHoles option;
+  options : inferenceOptions;
 }
 [@@deriving yojson]
 
@@ -117,9 +169,15 @@ let elrondSpec_of_yojson x = elrondSpec_of_yojson (to_list x)
 let elrondResult_to_yojson x = elrondResult_to_yojson x |> to_assoc
 let elrondResult_of_yojson x = elrondResult_of_yojson (to_list x)
 
+let rec into_elrondTree (t : int Utils.Tree.t) : elrondTree =
+  match t with
+  | Utils.Tree.Leaf -> Leaf
+  | Utils.Tree.Node (x, l, r) -> Node (x, into_elrondTree l, into_elrondTree r)
+
 let into_elrondValue (v : Pred.Value.t) : elrondValue =
   match v with
   | L l -> L l
+  | T t -> T (into_elrondTree t)
   | I i -> I i
   | B b -> B b
   | NotADt -> NotADt
@@ -192,5 +250,6 @@ let into_elrondResult (mii : Inference.SpecAbduction.multi_infer_result) :
            (fun k v acc -> (into_elrondSpec v, k) :: acc)
            res [])
 
-let into_elrondMessage (elrondResult : elrondResult) : elrondMessage =
-  Result elrondResult
+let into_elrondMessage (mode : elrondInferMode) (elrondResult : elrondResult) :
+    elrondMessage =
+  Result (mode, elrondResult)
@@ -0,0 +1,153 @@
+use std::fmt::Display;
+
+use itertools::Itertools;
+use serde_json::{json, Map, Value};
+
+use crate::{
+    ipc::{Counterexample, ElrondTpedvar, ElrondTree, ElrondValue},
+    vc::Vc,
+};
+
+/// A counterexample split along the client's signature. Inputs and outputs follow the
+/// order of the signature; `others` holds intermediate variables, ordered by name.
+#[derive(PartialEq, Debug, Clone)]
+pub struct GroupedCounterexample<'a> {
+    pub inputs: Vec<(&'a str, &'a ElrondValue)>,
+    pub outputs: Vec<(&'a str, &'a ElrondValue)>,
+    pub others: Vec<(&'a str, &'a ElrondValue)>,
+}
+
+impl Counterexample {
+    /// Groups the bindings into the client's `inputs` and `outputs`. Signature
+    /// variables the counterexample does not bind are skipped.
+    pub fn group<'a>(
+        &'a self,
+        inputs: &[ElrondTpedvar],
+        outputs: &[ElrondTpedvar],
+    ) -> GroupedCounterexample<'a> {
+        let pick = |sig: &[ElrondTpedvar]| {
+            sig.iter()
+                .filter_map(|ElrondTpedvar(_, name)| self.iter().find(|(n, _)| n == name))
+                .collect_vec()
+        };
+        let is_signature = |name: &str| {
+            inputs
+                .iter()
+                .chain(outputs)
+                .any(|ElrondTpedvar(_, n)| n == name)
+        };
+        GroupedCounterexample {
+            inputs: pick(inputs),
+            outputs: pick(outputs),
+            others: self.iter().filter(|(n, _)| !is_signature(n)).collect(),
+        }
+    }
+
+    /// Groups the bindings using the client signature recorded in `vc`.
+    pub fn group_by_vc<'a>(&'a self, vc: &Vc) -> GroupedCounterexample<'a> {
+        self.group(&vc.inputs, &vc.outputs)
+    }
+
+    /// A JSON object from variable names to plain JSON values, e.g.
+    /// `{"s1": [1, 2], "x": 3}`. Unlike the wire encoding, this is meant for people
+    /// and other tools.
+    pub fn to_json(&self) -> Value {
+        Value::Object(
+            self.iter()
+                .map(|(name, val)| (name.to_string(), value_json(val)))
+                .collect::<Map<_, _>>(),
+        )
+    }
+
+    /// An aligned two-column table of names and values.
+    pub fn table(&self) -> CounterexampleTable<'_> {
+        CounterexampleTable(self.iter().collect())
+    }
+}
+
+impl<'a> GroupedCounterexample<'a> {
+    /// Like [`Counterexample::to_json`], with one object per group.
+    pub fn to_json(&self) -> Value {
+        let group = |bindings: &[(&str, &ElrondValue)]| {
+            Value::Object(
+                bindings
+                    .iter()
+                    .map(|(name, val)| (name.to_string(), value_json(val)))
+                    .collect(),
+            )
+        };
+        json!({
+            "inputs": group(&self.inputs),
+            "outputs": group(&self.outputs),
+            "others": group(&self.others),
+        })
+    }
+
+    /// Like [`Counterexample::table`], with a heading row above each non-empty group.
+    pub fn table(&self) -> String {
+        let width = self
+            .inputs
+            .iter()
+            .chain(&self.outputs)
+            .chain(&self.others)
+            .map(|(n, _)| n.chars().count())
+            .max()
+            .unwrap_or(0);
+        [
+            ("inputs", &self.inputs),
+            ("outputs", &self.outputs),
+            ("others", &self.others),
+        ]
+        .into_iter()
+        .filter(|(_, bindings)| !bindings.is_empty())
+        .map(|(heading, bindings)| {
+            format!(
+                "{heading}:\n{}",
+                CounterexampleTable(bindings.clone()).with_width(width)
+            )
+        })
+        .join("")
+    }
+}
+
+fn value_json(val: &ElrondValue) -> Value {
+    match val {
+        ElrondValue::L(l) => json!(l),
+        ElrondValue::T(t) => tree_json(t),
+        ElrondValue::I(i) => json!(i),
+        ElrondValue::B(b) => json!(b),
+        ElrondValue::NotADt => Value::Null,
+    }
+}
+
+/// Trees render as nested `[left, label, right]` arrays, with `null` for leaves.
+fn tree_json(t: &ElrondTree) -> Value {
+    match t {
+        ElrondTree::Leaf => Value::Null,
+        ElrondTree::Node(label, left, right) => json!([tree_json(left), label, tree_json(right)]),
+    }
+}
+
+pub struct CounterexampleTable<'a>(Vec<(&'a str, &'a ElrondValue)>);
+
+impl<'a> CounterexampleTable<'a> {
+    fn with_width(self, width: usize) -> String {
+        self.0
+            .iter()
+            .map(|(name, val)| format!("  {name:<width$} | {val}\n"))
+            .join("")
+    }
+}
+
+impl<'a> Display for CounterexampleTable<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self.0.iter().map(|(n, _)| n.chars().count()).max();
+        let width = width.unwrap_or(0).max("name".len());
+        writeln!(f, "  {:<width$} | value", "name")?;
+        writeln!(f, "  {}-+-{}", "-".repeat(width), "-".repeat(5))?;
+        for (name, val) in &self.0 {
+            writeln!(f, "  {name:<width$} | {val}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,389 @@
+//! Structural diffs between the specs of two `ElrondResult`s, such as runs of a
+//! benchmark before and after an engine change.
+//!
+//! Specs are matched by function name. Within a spec the bodies are compared as
+//! trees: `And` and `Or` operands are aligned regardless of order, and everything
+//! else position by position.
+
+use std::{collections::BTreeSet, fmt::Display};
+
+use itertools::Itertools;
+
+use crate::{
+    ipc::{ElrondForallFormula, ElrondPred, ElrondResult, ElrondSimpleExpr, ElrondSpec},
+    pretty::{PrettyConfig, PrettyStyle},
+    visit::{walk_pred, Visitor},
+};
+
+/// How to render a diff.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum DiffStyle {
+    /// Plain text with ` `, `-` and `+` line prefixes.
+    #[default]
+    Unified,
+    /// The unified text colored with ANSI escapes: removed lines red, added lines
+    /// green and changed atoms yellow.
+    Color,
+}
+
+/// The connectives a [`PredDiff::Node`] descends through.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Connective {
+    Implies,
+    Ite,
+    Not,
+    And,
+    Or,
+    Iff,
+}
+
+impl Display for Connective {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Connective::Implies => write!(f, "implies"),
+            Connective::Ite => write!(f, "if"),
+            Connective::Not => write!(f, "!"),
+            Connective::And => write!(f, "&&"),
+            Connective::Or => write!(f, "||"),
+            Connective::Iff => write!(f, "iff"),
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum PredDiff {
+    Same(ElrondPred),
+    /// An `And` or `Or` operand only in the new predicate.
+    Added(ElrondPred),
+    /// An `And` or `Or` operand only in the old predicate.
+    Removed(ElrondPred),
+    /// Different atoms, or subformulas with different connectives, in the same place.
+    Changed(ElrondPred, ElrondPred),
+    /// The same connective with differing operands.
+    Node(Connective, Vec<PredDiff>),
+}
+
+impl PredDiff {
+    pub fn is_same(&self) -> bool {
+        matches!(self, PredDiff::Same(_))
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum SpecDiff {
+    Unchanged(ElrondSpec),
+    Added(ElrondSpec),
+    Removed(ElrondSpec),
+    Changed {
+        old: Box<ElrondSpec>,
+        new: Box<ElrondSpec>,
+        body: PredDiff,
+    },
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct FunctionDiff {
+    pub name: String,
+    pub diff: SpecDiff,
+}
+
+/// Every function of either result, in the old result's order followed by the
+/// functions only in the new one.
+#[derive(PartialEq, Debug, Clone)]
+pub struct ResultDiff(pub Vec<FunctionDiff>);
+
+impl ResultDiff {
+    /// The functions whose specs were added, removed or changed.
+    pub fn changes(&self) -> impl Iterator<Item = &FunctionDiff> {
+        self.0
+            .iter()
+            .filter(|f| !matches!(f.diff, SpecDiff::Unchanged(_)))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes().next().is_none()
+    }
+
+    pub fn render(&self, style: DiffStyle) -> String {
+        let mut lines = vec![];
+        for FunctionDiff { name, diff } in self.changes() {
+            match diff {
+                SpecDiff::Unchanged(_) => {}
+                SpecDiff::Added(spec) => {
+                    lines.push(Line::new(Tag::Added, 0, spec_line(name, spec)))
+                }
+                SpecDiff::Removed(spec) => {
+                    lines.push(Line::new(Tag::Removed, 0, spec_line(name, spec)))
+                }
+                SpecDiff::Changed { old, new, body } => {
+                    lines.push(Line::new(Tag::Header, 0, format!("@@ {name} @@")));
+                    let (old_header, new_header) = (header(old), header(new));
+                    if old_header == new_header {
+                        lines.push(Line::new(Tag::Context, 0, old_header));
+                    } else {
+                        lines.push(Line::new(Tag::Removed, 0, old_header));
+                        lines.push(Line::new(Tag::Added, 0, new_header));
+                    }
+                    pred_lines(body, 1, &mut lines);
+                }
+            }
+        }
+        lines.iter().map(|line| line.render(style)).join("\n")
+    }
+}
+
+impl Display for ResultDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render(DiffStyle::Unified))
+    }
+}
+
+impl ElrondResult {
+    /// The changes from this result's specs to `new`'s. Counterexamples carry no
+    /// specs, so a `Cex` result counts as having none.
+    pub fn diff(&self, new: &ElrondResult) -> ResultDiff {
+        fn specs(result: &ElrondResult) -> &[(ElrondSpec, String)] {
+            match result {
+                ElrondResult::Result(specs) => specs,
+                ElrondResult::Cex(_) => &[],
+            }
+        }
+        let (old, new) = (specs(self), specs(new));
+        let find = |specs: &'_ [(ElrondSpec, String)], name: &str| {
+            specs
+                .iter()
+                .find_map(|(spec, n)| (n == name).then(|| spec.clone()))
+        };
+        let changed = old.iter().map(|(spec, name)| FunctionDiff {
+            name: name.clone(),
+            diff: match find(new, name) {
+                Some(new) => spec.diff(&new),
+                None => SpecDiff::Removed(spec.clone()),
+            },
+        });
+        let added = new
+            .iter()
+            .filter(|(_, name)| find(old, name).is_none())
+            .map(|(spec, name)| FunctionDiff {
+                name: name.clone(),
+                diff: SpecDiff::Added(spec.clone()),
+            });
+        ResultDiff(changed.chain(added).collect())
+    }
+}
+
+impl ElrondSpec {
+    /// Specs that differ only in the order of `And` and `Or` operands are unchanged.
+    pub fn diff(&self, new: &ElrondSpec) -> SpecDiff {
+        let body = self.1 .1.diff(&new.1 .1);
+        if body.is_same() && header(self) == header(new) {
+            return SpecDiff::Unchanged(self.clone());
+        }
+        SpecDiff::Changed {
+            old: Box::new(self.clone()),
+            new: Box::new(new.clone()),
+            body,
+        }
+    }
+}
+
+impl ElrondPred {
+    pub fn diff(&self, new: &ElrondPred) -> PredDiff {
+        use ElrondPred::*;
+        if self == new {
+            return PredDiff::Same(self.clone());
+        }
+        let node = |connective, pairs: Vec<(&ElrondPred, &ElrondPred)>| {
+            PredDiff::Node(
+                connective,
+                pairs.into_iter().map(|(o, n)| o.diff(n)).collect(),
+            )
+        };
+        let diff = match (self, new) {
+            (Implies(o1, o2), Implies(n1, n2)) => {
+                node(Connective::Implies, vec![(o1, n1), (o2, n2)])
+            }
+            (Iff(o1, o2), Iff(n1, n2)) => node(Connective::Iff, vec![(o1, n1), (o2, n2)]),
+            (Ite(o1, o2, o3), Ite(n1, n2, n3)) => {
+                node(Connective::Ite, vec![(o1, n1), (o2, n2), (o3, n3)])
+            }
+            (Not(o), Not(n)) => node(Connective::Not, vec![(o, n)]),
+            (And(o), And(n)) => PredDiff::Node(Connective::And, align(o, n)),
+            (Or(o), Or(n)) => PredDiff::Node(Connective::Or, align(o, n)),
+            (old, new) => PredDiff::Changed(old.clone(), new.clone()),
+        };
+        match diff {
+            // Reordered operands
+            PredDiff::Node(_, children) if children.iter().all(PredDiff::is_same) => {
+                PredDiff::Same(self.clone())
+            }
+            diff => diff,
+        }
+    }
+}
+
+/// Diffs the operands of a junction regardless of order. Equal operands are matched
+/// first, then the remaining ones are paired by the atoms they share; whatever is
+/// left over was removed or added.
+fn align(old: &[ElrondPred], new: &[ElrondPred]) -> Vec<PredDiff> {
+    let mut matched: Vec<Option<usize>> = vec![None; old.len()];
+    let mut used = vec![false; new.len()];
+    for (i, o) in old.iter().enumerate() {
+        if let Some(j) = (0..new.len()).find(|&j| !used[j] && new[j] == *o) {
+            matched[i] = Some(j);
+            used[j] = true;
+        }
+    }
+
+    let atoms = |p: &ElrondPred| {
+        let mut atoms = Atoms::default();
+        atoms.visit_pred(p);
+        atoms.0
+    };
+    let old_atoms = old.iter().map(atoms).collect_vec();
+    let new_atoms = new.iter().map(atoms).collect_vec();
+    loop {
+        let best = (0..old.len())
+            .filter(|&i| matched[i].is_none())
+            .cartesian_product((0..new.len()).filter(|&j| !used[j]))
+            .map(|(i, j)| {
+                (
+                    similarity(&old[i], &new[j], &old_atoms[i], &new_atoms[j]),
+                    i,
+                    j,
+                )
+            })
+            .filter(|(score, _, _)| *score > 0)
+            // The first of the best pairs, so ties go to the earliest operands
+            .max_by_key(|(score, i, j)| (*score, std::cmp::Reverse((*i, *j))));
+        let Some((_, i, j)) = best else { break };
+        matched[i] = Some(j);
+        used[j] = true;
+    }
+
+    let diffs = old.iter().zip(&matched).map(|(o, m)| match m {
+        Some(j) => o.diff(&new[*j]),
+        None => PredDiff::Removed(o.clone()),
+    });
+    let added = new
+        .iter()
+        .zip(&used)
+        .filter(|(_, used)| !**used)
+        .map(|(n, _)| PredDiff::Added(n.clone()));
+    diffs.chain(added).collect()
+}
+
+/// How alike two operands are: for atoms, whether they apply the same operator and
+/// how many arguments agree; otherwise how many atoms they share.
+fn similarity(
+    old: &ElrondPred,
+    new: &ElrondPred,
+    old_atoms: &BTreeSet<String>,
+    new_atoms: &BTreeSet<String>,
+) -> usize {
+    match (old, new) {
+        (
+            ElrondPred::Atom(ElrondSimpleExpr::Op(_, o, o_args)),
+            ElrondPred::Atom(ElrondSimpleExpr::Op(_, n, n_args)),
+        ) => {
+            if o == n {
+                1 + o_args.iter().zip(n_args).filter(|(o, n)| o == n).count()
+            } else {
+                0
+            }
+        }
+        _ => old_atoms.intersection(new_atoms).count(),
+    }
+}
+
+#[derive(Default)]
+struct Atoms(BTreeSet<String>);
+
+impl Visitor for Atoms {
+    fn visit_pred(&mut self, p: &ElrondPred) {
+        match p {
+            ElrondPred::Atom(e) => {
+                self.0.insert(e.to_string());
+            }
+            p => walk_pred(self, p),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+enum Tag {
+    Header,
+    Context,
+    Removed,
+    Added,
+    /// The old and new sides of a changed atom.
+    ChangedOld,
+    ChangedNew,
+}
+
+struct Line {
+    tag: Tag,
+    depth: usize,
+    text: String,
+}
+
+impl Line {
+    fn new(tag: Tag, depth: usize, text: String) -> Self {
+        Line { tag, depth, text }
+    }
+
+    fn render(&self, style: DiffStyle) -> String {
+        let prefix = match self.tag {
+            Tag::Header => "",
+            Tag::Context => " ",
+            Tag::Removed | Tag::ChangedOld => "-",
+            Tag::Added | Tag::ChangedNew => "+",
+        };
+        let line = format!("{prefix}{}{}", "  ".repeat(self.depth), self.text);
+        let color = match (style, self.tag) {
+            (DiffStyle::Unified, _) | (DiffStyle::Color, Tag::Context) => return line,
+            (DiffStyle::Color, Tag::Header) => "36",
+            (DiffStyle::Color, Tag::Removed) => "31",
+            (DiffStyle::Color, Tag::Added) => "32",
+            (DiffStyle::Color, Tag::ChangedOld | Tag::ChangedNew) => "33",
+        };
+        format!("\x1b[{color}m{line}\x1b[0m")
+    }
+}
+
+fn flat(p: &ElrondPred) -> String {
+    p.pretty(&PrettyConfig::flat(PrettyStyle::OCaml))
+}
+
+/// The spec without its body, e.g. `(l1 : int list) (u : int) forall u_0 : int .`.
+fn header(spec: &ElrondSpec) -> String {
+    let ElrondSpec(args, ElrondForallFormula(free_vars, _)) = spec;
+    let args = args.iter().map(|a| format!("({a})")).join(" ");
+    if free_vars.is_empty() {
+        format!("fun {args} ->")
+    } else {
+        format!("fun {args} -> forall {} .", free_vars.iter().join(", "))
+    }
+}
+
+fn spec_line(name: &str, spec: &ElrondSpec) -> String {
+    format!("{name} : {} {}", header(spec), flat(&spec.1 .1))
+}
+
+fn pred_lines(diff: &PredDiff, depth: usize, lines: &mut Vec<Line>) {
+    match diff {
+        PredDiff::Same(p) => lines.push(Line::new(Tag::Context, depth, flat(p))),
+        PredDiff::Added(p) => lines.push(Line::new(Tag::Added, depth, flat(p))),
+        PredDiff::Removed(p) => lines.push(Line::new(Tag::Removed, depth, flat(p))),
+        PredDiff::Changed(old, new) => {
+            lines.push(Line::new(Tag::ChangedOld, depth, flat(old)));
+            lines.push(Line::new(Tag::ChangedNew, depth, flat(new)));
+        }
+        PredDiff::Node(connective, children) => {
+            lines.push(Line::new(Tag::Context, depth, connective.to_string()));
+            for child in children {
+                pred_lines(child, depth + 1, lines);
+            }
+        }
+    }
+}
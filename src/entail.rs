@@ -0,0 +1,343 @@
+//! Bounded entailment and equivalence checking between specs.
+//!
+//! Both specs are evaluated on every input within a [`Bound`]: lists and trees up
+//! to a size, over a small range of ints. The answer is exact within the bound and
+//! only a sign of the relation beyond it.
+
+use std::{collections::BTreeSet, fmt::Display};
+
+use itertools::Itertools;
+
+use crate::{
+    eval::{Env, EvalError},
+    ipc::{Counterexample, ElrondSpec, ElrondTpedvar, ElrondTree, ElrondType, ElrondValue},
+    predicates::ElrondDatatype,
+    subst::{fresh_name, SubstError},
+};
+
+/// The inputs to enumerate.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Bound {
+    /// Inclusive bound on list lengths and tree sizes. Defaults to `3`.
+    pub max_size: usize,
+    /// Inclusive bounds on ints and elements. Defaults to `0..=2`.
+    pub min_int: i64,
+    pub max_int: i64,
+    /// What to enumerate for abstract types such as `Customstk.t`. Defaults to lists.
+    pub generic: ElrondDatatype,
+}
+
+impl Default for Bound {
+    fn default() -> Self {
+        Bound {
+            max_size: 3,
+            min_int: 0,
+            max_int: 2,
+            generic: ElrondDatatype::List,
+        }
+    }
+}
+
+impl Bound {
+    fn ints(&self) -> Vec<i64> {
+        (self.min_int..=self.max_int).collect()
+    }
+
+    /// Every list up to `max_size`, shortest first.
+    fn lists(&self) -> Vec<Vec<i64>> {
+        let ints = self.ints();
+        let mut lists = vec![vec![]];
+        let mut last = vec![vec![]];
+        for _ in 0..self.max_size {
+            last = last
+                .iter()
+                .cartesian_product(&ints)
+                .map(|(l, x)| [l.as_slice(), &[*x]].concat())
+                .collect();
+            lists.extend(last.iter().cloned());
+        }
+        lists
+    }
+
+    /// Every tree of exactly `size` nodes.
+    fn trees_of_size(&self, size: usize) -> Vec<ElrondTree> {
+        if size == 0 {
+            return vec![ElrondTree::Leaf];
+        }
+        let ints = self.ints();
+        (0..size)
+            .flat_map(|left| {
+                let lefts = self.trees_of_size(left);
+                let rights = self.trees_of_size(size - 1 - left);
+                let ints = ints.clone();
+                lefts
+                    .into_iter()
+                    .cartesian_product(rights)
+                    .cartesian_product(ints)
+                    .map(|((l, r), x)| ElrondTree::node(x, l, r))
+                    .collect_vec()
+            })
+            .collect()
+    }
+
+    /// Every value of type `ty`, smallest first.
+    fn values(&self, ty: &ElrondType) -> Result<Vec<ElrondValue>, EntailError> {
+        match ty {
+            ElrondType::Int => Ok(self.ints().into_iter().map(ElrondValue::I).collect()),
+            ElrondType::Bool => Ok(vec![ElrondValue::B(false), ElrondValue::B(true)]),
+            ElrondType::IntList => Ok(self.lists().into_iter().map(ElrondValue::L).collect()),
+            ElrondType::IntTree => Ok((0..=self.max_size)
+                .flat_map(|size| self.trees_of_size(size))
+                .map(ElrondValue::T)
+                .collect()),
+            ElrondType::Generic(_) => self.values(&self.generic.elrond_type()),
+            _ => Err(EntailError::Unsupported(ty.clone())),
+        }
+    }
+
+    /// The type values of `ty` are enumerated at.
+    fn value_type(&self, ty: &ElrondType) -> ElrondType {
+        match ty {
+            ElrondType::Generic(_) => self.generic.elrond_type(),
+            ty => ty.clone(),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum EntailError {
+    /// The specs take different numbers of arguments.
+    Arity {
+        left: usize,
+        right: usize,
+    },
+    /// The arguments at `position` have different types.
+    ArgType {
+        position: usize,
+        left: ElrondType,
+        right: ElrondType,
+    },
+    /// The right spec's arguments could not be renamed to the left's.
+    Rename(SubstError),
+    /// A type there is no enumeration for, such as a function type.
+    Unsupported(ElrondType),
+    Eval(EvalError),
+}
+
+impl Display for EntailError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EntailError::Arity { left, right } => {
+                write!(f, "the specs take {left} and {right} arguments")
+            }
+            EntailError::ArgType {
+                position,
+                left,
+                right,
+            } => write!(f, "argument {position} has types {left} and {right}"),
+            EntailError::Rename(e) => write!(f, "cannot align the arguments: {e}"),
+            EntailError::Unsupported(ty) => write!(f, "cannot enumerate values of type {ty}"),
+            EntailError::Eval(e) => write!(f, "could not evaluate a spec: {e}"),
+        }
+    }
+}
+
+impl From<EvalError> for EntailError {
+    fn from(e: EvalError) -> Self {
+        EntailError::Eval(e)
+    }
+}
+
+impl From<SubstError> for EntailError {
+    fn from(e: SubstError) -> Self {
+        EntailError::Rename(e)
+    }
+}
+
+/// How two specs relate within a bound.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Relation {
+    Equivalent,
+    /// The left spec implies the right one, but not the other way around.
+    Stronger,
+    /// The right spec implies the left one, but not the other way around.
+    Weaker,
+    Incomparable,
+}
+
+impl Display for Relation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Relation::Equivalent => write!(f, "A ⇔ B"),
+            Relation::Stronger => write!(f, "A ⊨ B, B ⊭ A"),
+            Relation::Weaker => write!(f, "B ⊨ A, A ⊭ B"),
+            Relation::Incomparable => write!(f, "A ⊭ B, B ⊭ A"),
+        }
+    }
+}
+
+/// The result of comparing spec A with spec B on every input within a bound.
+/// Inputs are named after A's arguments.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Comparison {
+    /// How many inputs were evaluated.
+    pub inputs: usize,
+    /// The first input satisfying A but not B, which refutes A ⊨ B.
+    pub only_left: Option<Counterexample>,
+    /// The first input satisfying B but not A, which refutes B ⊨ A.
+    pub only_right: Option<Counterexample>,
+}
+
+impl Comparison {
+    pub fn relation(&self) -> Relation {
+        match (&self.only_left, &self.only_right) {
+            (None, None) => Relation::Equivalent,
+            (None, Some(_)) => Relation::Stronger,
+            (Some(_), None) => Relation::Weaker,
+            (Some(_), Some(_)) => Relation::Incomparable,
+        }
+    }
+
+    pub fn left_entails_right(&self) -> bool {
+        self.only_left.is_none()
+    }
+
+    pub fn right_entails_left(&self) -> bool {
+        self.only_right.is_none()
+    }
+
+    pub fn equivalent(&self) -> bool {
+        self.relation() == Relation::Equivalent
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} on {} inputs", self.relation(), self.inputs)?;
+        if let Some(cex) = &self.only_left {
+            write!(f, "A but not B:\n{cex}")?;
+        }
+        if let Some(cex) = &self.only_right {
+            write!(f, "B but not A:\n{cex}")?;
+        }
+        Ok(())
+    }
+}
+
+impl ElrondSpec {
+    /// Evaluates this spec and `other` on every input within `bound`. Arguments are
+    /// matched by position, so `other`'s are renamed to this spec's first.
+    pub fn compare(&self, other: &ElrondSpec, bound: &Bound) -> Result<Comparison, EntailError> {
+        let ElrondSpec(args, formula) = self;
+        let other = align(args, other, bound)?;
+        let domains: Vec<_> = args
+            .iter()
+            .map(|ElrondTpedvar(ty, _)| bound.values(ty))
+            .try_collect()?;
+        // `multi_cartesian_product` of no domains is empty, not one empty input
+        let inputs: Box<dyn Iterator<Item = Vec<ElrondValue>>> = if domains.is_empty() {
+            Box::new(std::iter::once(vec![]))
+        } else {
+            Box::new(domains.into_iter().multi_cartesian_product())
+        };
+
+        let mut comparison = Comparison {
+            inputs: 0,
+            only_left: None,
+            only_right: None,
+        };
+        for values in inputs {
+            let env: Env = args
+                .iter()
+                .zip(&values)
+                .map(|(ElrondTpedvar(_, name), v)| (name.clone(), v.clone()))
+                .collect();
+            comparison.inputs += 1;
+            let left = formula.eval(&env)?;
+            let right = other.1.eval(&env)?;
+            let witness = || {
+                Counterexample::new(
+                    values
+                        .iter()
+                        .cloned()
+                        .zip(args.iter().map(|ElrondTpedvar(_, name)| name.clone()))
+                        .collect(),
+                )
+            };
+            if left && !right && comparison.only_left.is_none() {
+                comparison.only_left = Some(witness());
+            }
+            if right && !left && comparison.only_right.is_none() {
+                comparison.only_right = Some(witness());
+            }
+        }
+        Ok(comparison)
+    }
+
+    /// An input within `bound` satisfying this spec but not `other`, or `None` if
+    /// this spec entails `other` within the bound.
+    pub fn entails(
+        &self,
+        other: &ElrondSpec,
+        bound: &Bound,
+    ) -> Result<Option<Counterexample>, EntailError> {
+        Ok(self.compare(other, bound)?.only_left)
+    }
+
+    pub fn equivalent(&self, other: &ElrondSpec, bound: &Bound) -> Result<bool, EntailError> {
+        Ok(self.compare(other, bound)?.equivalent())
+    }
+}
+
+/// `spec` with its arguments renamed to `args`, position by position.
+fn align(
+    args: &[ElrondTpedvar],
+    spec: &ElrondSpec,
+    bound: &Bound,
+) -> Result<ElrondSpec, EntailError> {
+    let other_args = &spec.0;
+    if args.len() != other_args.len() {
+        return Err(EntailError::Arity {
+            left: args.len(),
+            right: other_args.len(),
+        });
+    }
+    for (position, (ElrondTpedvar(left, _), ElrondTpedvar(right, _))) in
+        args.iter().zip(other_args).enumerate()
+    {
+        if bound.value_type(left) != bound.value_type(right) {
+            return Err(EntailError::ArgType {
+                position,
+                left: left.clone(),
+                right: right.clone(),
+            });
+        }
+    }
+    // Renaming one argument at a time could capture another, so rename all at once
+    // after moving any quantified variable out of the way of the new names
+    let renaming = other_args
+        .iter()
+        .zip(args)
+        .map(|(ElrondTpedvar(_, from), ElrondTpedvar(_, to))| (from.as_str(), to.as_str()))
+        .collect_vec();
+    let mut spec = spec.clone();
+    let targets: BTreeSet<String> = args
+        .iter()
+        .map(|ElrondTpedvar(_, name)| name.clone())
+        .collect();
+    let mut avoid = spec.bound_vars();
+    avoid.extend(spec.free_vars());
+    avoid.extend(args.iter().map(|ElrondTpedvar(_, name)| name.clone()));
+    let clashes = spec
+        .1
+        .bound_vars()
+        .intersection(&targets)
+        .cloned()
+        .collect_vec();
+    for clash in clashes {
+        let fresh = fresh_name(&clash, &avoid);
+        avoid.insert(fresh.clone());
+        spec = spec.alpha_rename(&[(&clash, &fresh)])?;
+    }
+    Ok(spec.alpha_rename(&renaming)?)
+}
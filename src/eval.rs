@@ -0,0 +1,319 @@
+//! Evaluates specs over concrete values, without a round trip to Elrond.
+
+use std::{collections::HashMap, fmt::Display};
+
+use itertools::{Either, Itertools};
+
+use crate::ipc::{
+    Counterexample, ElrondForallFormula, ElrondKnownPredicate, ElrondLiteral, ElrondPred,
+    ElrondSimpleExpr, ElrondTree, ElrondType, ElrondValue, FreeVar,
+};
+
+/// Values for the variables of a spec.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct Env(HashMap<String, ElrondValue>);
+
+impl Env {
+    pub fn new() -> Self {
+        Env::default()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ElrondValue> {
+        self.0.get(name)
+    }
+
+    pub fn insert(&mut self, name: impl Into<String>, value: ElrondValue) -> Option<ElrondValue> {
+        self.0.insert(name.into(), value)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ElrondValue)> {
+        self.0.iter().map(|(n, v)| (n.as_str(), v))
+    }
+
+    /// The values a quantified variable of type `ty` ranges over: every value of
+    /// that type occurring in the environment, including list elements for `int`.
+    pub fn domain(&self, ty: &ElrondType) -> Vec<ElrondValue> {
+        let values = self.0.values();
+        match ty {
+            ElrondType::Bool => vec![ElrondValue::B(false), ElrondValue::B(true)],
+            ElrondType::Int => values
+                .flat_map(|v| match v {
+                    ElrondValue::I(i) => vec![*i],
+                    ElrondValue::L(l) => l.clone(),
+                    ElrondValue::T(t) => t.labels(),
+                    _ => vec![],
+                })
+                .sorted()
+                .dedup()
+                .map(ElrondValue::I)
+                .collect(),
+            ElrondType::IntList => values
+                .filter_map(|v| match v {
+                    ElrondValue::L(l) => Some(l.clone()),
+                    _ => None,
+                })
+                .sorted()
+                .dedup()
+                .map(ElrondValue::L)
+                .collect(),
+            ElrondType::IntTree => values
+                .filter_map(|v| match v {
+                    ElrondValue::T(t) => Some(t.clone()),
+                    _ => None,
+                })
+                .sorted()
+                .dedup()
+                .map(ElrondValue::T)
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Every assignment of `vars` over their [`Env::domain`]s, each with this
+    /// environment extended by it. No variables give the single empty assignment.
+    pub fn assignments<'a>(
+        &'a self,
+        vars: &'a [FreeVar],
+    ) -> impl Iterator<Item = (Env, Vec<(FreeVar, ElrondValue)>)> + 'a {
+        let values = if vars.is_empty() {
+            Either::Left(std::iter::once(vec![]))
+        } else {
+            Either::Right(
+                vars.iter()
+                    .map(|FreeVar(ty, _)| self.domain(ty))
+                    .multi_cartesian_product(),
+            )
+        };
+        values.map(move |values| {
+            let mut env = self.clone();
+            for (FreeVar(_, name), val) in vars.iter().zip(&values) {
+                env.insert(name.clone(), val.clone());
+            }
+            (env, vars.iter().cloned().zip(values).collect())
+        })
+    }
+}
+
+impl FromIterator<(String, ElrondValue)> for Env {
+    fn from_iter<T: IntoIterator<Item = (String, ElrondValue)>>(iter: T) -> Self {
+        Env(iter.into_iter().collect())
+    }
+}
+
+impl From<&Counterexample> for Env {
+    fn from(cex: &Counterexample) -> Self {
+        cex.iter()
+            .map(|(name, val)| (name.to_string(), val.clone()))
+            .collect()
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum EvalError {
+    UnboundVariable(String),
+    /// An operator that is neither a comparison nor a known predicate.
+    UnknownOp(String),
+    /// An operator applied to arguments of the wrong shape.
+    BadArguments {
+        op: String,
+        args: Vec<String>,
+    },
+    /// An expression the evaluator has no semantics for, such as a tuple.
+    Unsupported(String),
+}
+
+impl Display for EvalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EvalError::UnboundVariable(name) => write!(f, "unbound variable {name}"),
+            EvalError::UnknownOp(op) => write!(f, "unknown operator {op}"),
+            EvalError::BadArguments { op, args } => {
+                write!(f, "{op} cannot be applied to {}", args.iter().join(", "))
+            }
+            EvalError::Unsupported(e) => write!(f, "cannot evaluate {e}"),
+        }
+    }
+}
+
+fn bad_arguments(op: impl Display, args: &[ElrondValue]) -> EvalError {
+    EvalError::BadArguments {
+        op: op.to_string(),
+        args: args.iter().map(ToString::to_string).collect(),
+    }
+}
+
+impl ElrondSimpleExpr {
+    pub fn eval(&self, env: &Env) -> Result<ElrondValue, EvalError> {
+        match self {
+            ElrondSimpleExpr::Literal(_, lit) => Ok(match lit {
+                ElrondLiteral::Int(i) => ElrondValue::I(*i),
+                ElrondLiteral::Bool(b) => ElrondValue::B(*b),
+                ElrondLiteral::IntList(l) => ElrondValue::L(l.clone()),
+            }),
+            ElrondSimpleExpr::Var(_, name) => env
+                .get(name)
+                .cloned()
+                .ok_or_else(|| EvalError::UnboundVariable(name.clone())),
+            ElrondSimpleExpr::Op(_, op, args) => {
+                let args = args.iter().map(|a| a.eval(env)).try_collect()?;
+                apply_op(op, args)
+            }
+            ElrondSimpleExpr::Tuple(_) => Err(EvalError::Unsupported(self.to_string())),
+        }
+    }
+}
+
+fn apply_op(op: &str, args: Vec<ElrondValue>) -> Result<ElrondValue, EvalError> {
+    let compare = |cmp: fn(&i64, &i64) -> bool| match args.as_slice() {
+        [ElrondValue::I(i), ElrondValue::I(j)] => Ok(ElrondValue::B(cmp(i, j))),
+        _ => Err(bad_arguments(op, &args)),
+    };
+    match op {
+        "==" => match args.as_slice() {
+            [v1, v2] => Ok(ElrondValue::B(v1 == v2)),
+            _ => Err(bad_arguments(op, &args)),
+        },
+        "!=" => match args.as_slice() {
+            [v1, v2] => Ok(ElrondValue::B(v1 != v2)),
+            _ => Err(bad_arguments(op, &args)),
+        },
+        "<=" => compare(i64::le),
+        ">=" => compare(i64::ge),
+        "<" => compare(i64::lt),
+        ">" => compare(i64::gt),
+        _ => {
+            let p: ElrondKnownPredicate = op
+                .parse()
+                .map_err(|_| EvalError::UnknownOp(op.to_string()))?;
+            p.apply(&args).map(ElrondValue::B)
+        }
+    }
+}
+
+impl ElrondKnownPredicate {
+    /// Applies the predicate to concrete values, datatype first.
+    pub fn apply(&self, args: &[ElrondValue]) -> Result<bool, EvalError> {
+        use ElrondKnownPredicate::*;
+        match (self, args) {
+            (member, [ElrondValue::L(l), ElrondValue::I(u)]) => Ok(l.contains(u)),
+            (head, [ElrondValue::L(l), ElrondValue::I(u)]) => Ok(l.first() == Some(u)),
+            (length, [ElrondValue::L(l), ElrondValue::I(n)]) => Ok(l.len() as i64 == *n),
+            (order, [ElrondValue::L(l), ElrondValue::I(u), ElrondValue::I(v)]) => Ok(l
+                .iter()
+                .position(|x| x == u)
+                .is_some_and(|i| l[i + 1..].contains(v))),
+            (once, [ElrondValue::L(l), ElrondValue::I(u)]) => {
+                Ok(l.iter().filter(|x| *x == u).count() == 1)
+            }
+            (sorted, [ElrondValue::L(l)]) => Ok(l.windows(2).all(|w| w[0] <= w[1])),
+            (member, [ElrondValue::T(t), ElrondValue::I(u)]) => Ok(t.contains(*u)),
+            (once, [ElrondValue::T(t), ElrondValue::I(u)]) => {
+                Ok(t.labels().iter().filter(|x| *x == u).count() == 1)
+            }
+            (root, [ElrondValue::T(t), ElrondValue::I(u)]) => {
+                Ok(matches!(t, ElrondTree::Node(x, _, _) if x == u))
+            }
+            (left, [ElrondValue::T(t), ElrondValue::I(u), ElrondValue::I(v)]) => {
+                Ok(t.nodes().any(|(x, l, _)| x == *u && l.contains(*v)))
+            }
+            (right, [ElrondValue::T(t), ElrondValue::I(u), ElrondValue::I(v)]) => {
+                Ok(t.nodes().any(|(x, _, r)| x == *u && r.contains(*v)))
+            }
+            (ance, [ElrondValue::T(t), ElrondValue::I(u), ElrondValue::I(v)]) => Ok(t
+                .nodes()
+                .any(|(x, l, r)| x == *u && (l.contains(*v) || r.contains(*v)))),
+            (para, [ElrondValue::T(t), ElrondValue::I(u), ElrondValue::I(v)]) => {
+                Ok(t.nodes().any(|(_, l, r)| l.contains(*u) && r.contains(*v)))
+            }
+            _ => Err(bad_arguments(self, args)),
+        }
+    }
+}
+
+impl ElrondTree {
+    /// Every node as `(label, left, right)`, in preorder.
+    pub fn nodes(&self) -> impl Iterator<Item = (i64, &ElrondTree, &ElrondTree)> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || loop {
+            match stack.pop()? {
+                ElrondTree::Leaf => continue,
+                ElrondTree::Node(x, l, r) => {
+                    stack.push(r);
+                    stack.push(l);
+                    return Some((*x, &**l, &**r));
+                }
+            }
+        })
+    }
+
+    /// The labels of every node, in preorder.
+    pub fn labels(&self) -> Vec<i64> {
+        self.nodes().map(|(x, _, _)| x).collect()
+    }
+
+    pub fn contains(&self, u: i64) -> bool {
+        self.nodes().any(|(x, _, _)| x == u)
+    }
+}
+
+impl ElrondPred {
+    pub fn eval(&self, env: &Env) -> Result<bool, EvalError> {
+        match self {
+            ElrondPred::True => Ok(true),
+            ElrondPred::Atom(e) => match e.eval(env)? {
+                ElrondValue::B(b) => Ok(b),
+                v => Err(EvalError::BadArguments {
+                    op: e.to_string(),
+                    args: vec![v.to_string()],
+                }),
+            },
+            ElrondPred::Implies(p1, p2) => Ok(!p1.eval(env)? || p2.eval(env)?),
+            ElrondPred::Ite(c, p1, p2) => {
+                if c.eval(env)? {
+                    p1.eval(env)
+                } else {
+                    p2.eval(env)
+                }
+            }
+            ElrondPred::Not(p) => Ok(!p.eval(env)?),
+            ElrondPred::And(p_vec) => {
+                for p in p_vec {
+                    if !p.eval(env)? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            }
+            ElrondPred::Or(p_vec) => {
+                for p in p_vec {
+                    if p.eval(env)? {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+            ElrondPred::Iff(p1, p2) => Ok(p1.eval(env)? == p2.eval(env)?),
+        }
+    }
+}
+
+impl ElrondForallFormula {
+    pub fn eval(&self, env: &Env) -> Result<bool, EvalError> {
+        Ok(self.find_violation(env)?.is_none())
+    }
+
+    /// Enumerates the quantified variables over [`Env::domain`] and returns the first
+    /// assignment under which the body is false.
+    pub fn find_violation(
+        &self,
+        env: &Env,
+    ) -> Result<Option<Vec<(FreeVar, ElrondValue)>>, EvalError> {
+        let ElrondForallFormula(vars, body) = self;
+        for (env, assignment) in env.assignments(vars) {
+            if !body.eval(&env)? {
+                return Ok(Some(assignment));
+            }
+        }
+        Ok(None)
+    }
+}
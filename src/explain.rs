@@ -0,0 +1,297 @@
+//! Explains counterexamples by evaluating the client's post spec on them.
+//!
+//! Counterexamples bind the variables of the client's VC, such as `s1`, `s2` and
+//! `il_0`, rather than the arguments of the spec they violate, so [`Bindings`] give
+//! each argument its value. Arguments left unbound are universally quantified, like
+//! the quantified variables of the engine's specs: a spec is violated when some
+//! values for them falsify it. An assertion's pre and post specs share arguments of
+//! the same name, so it is violated when one choice of values satisfies the pre
+//! spec and falsifies the post spec. [`ElrondAssertion::to_smt2`] declares the
+//! arguments as shared constants to the same effect.
+
+use std::fmt::Display;
+
+use itertools::Itertools;
+
+use crate::{
+    eval::{Env, EvalError},
+    ipc::{
+        Counterexample, ElrondAssertion, ElrondForallFormula, ElrondPred, ElrondResult,
+        ElrondSimpleExpr, ElrondSpec, ElrondTpedvar, ElrondValue, FreeVar,
+    },
+    vc::{Vc, VcAssertion},
+    visit::Visitor,
+};
+
+/// The value of each bound spec argument, as an expression over the variables of
+/// a counterexample.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct Bindings(pub Vec<(String, ElrondSimpleExpr)>);
+
+impl Bindings {
+    /// Binds each of `args` to the variable of the same name.
+    pub fn by_name(args: &[ElrondTpedvar]) -> Self {
+        Bindings(
+            args.iter()
+                .map(|ElrondTpedvar(ty, name)| {
+                    (
+                        name.clone(),
+                        ElrondSimpleExpr::Var(ty.clone(), name.clone()),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    /// Binds the leading `args` by position to `actuals`; the rest stay unbound.
+    pub fn positional(args: &[ElrondTpedvar], actuals: &[ElrondSimpleExpr]) -> Self {
+        Bindings(
+            args.iter()
+                .zip(actuals)
+                .map(|(ElrondTpedvar(_, name), actual)| (name.clone(), actual.clone()))
+                .collect(),
+        )
+    }
+
+    /// Binds `args` by position to the actuals of the VC's post, such as `s1 s2 il_0`
+    /// in `concat_post s1 s2 il_0`, or returns `None` if the post is not one call.
+    pub fn post_call(args: &[ElrondTpedvar], vc: &Vc) -> Option<Self> {
+        match &vc.post {
+            VcAssertion::SpecApply(_, actuals) => Some(Bindings::positional(args, actuals)),
+            _ => None,
+        }
+    }
+
+    pub fn binds(&self, arg: &str) -> bool {
+        self.0.iter().any(|(name, _)| name == arg)
+    }
+
+    /// The counterexample variables the bound arguments are read from.
+    pub fn vars(&self) -> Vec<String> {
+        self.0
+            .iter()
+            .flat_map(|(_, actual)| actual.free_vars())
+            .unique()
+            .collect()
+    }
+
+    /// The values of the bound arguments in `cex`. A variable missing from `cex` is
+    /// an error rather than quantified, since it is meant to be fixed.
+    pub fn env(&self, cex: &Counterexample) -> Result<Env, EvalError> {
+        let cex = Env::from(cex);
+        self.0
+            .iter()
+            .map(|(name, actual)| Ok((name.clone(), actual.eval(&cex)?)))
+            .collect()
+    }
+}
+
+/// Why a counterexample violates a post spec.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Explanation {
+    pub cex: Counterexample,
+    /// Values for the spec's quantified variables, and for the arguments left
+    /// unbound, under which the spec is false.
+    pub quantified: Vec<(FreeVar, ElrondValue)>,
+    /// The path from the spec's body down to the minimal failing subformula.
+    pub trace: Vec<TraceStep>,
+    /// Every atom of the spec, in order of appearance, with its value.
+    pub atoms: Vec<(ElrondSimpleExpr, bool)>,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct TraceStep {
+    /// A false subformula.
+    pub formula: ElrondPred,
+    /// Why it is false.
+    pub reason: String,
+}
+
+impl Explanation {
+    /// The smallest subformula whose falsity explains the violation.
+    pub fn failing(&self) -> &ElrondPred {
+        &self.trace.last().expect("a trace is never empty").formula
+    }
+}
+
+impl ElrondSpec {
+    /// Explains why `cex`, which binds every argument by name, violates this spec,
+    /// or returns `None` if it does not.
+    pub fn explain(&self, cex: &Counterexample) -> Result<Option<Explanation>, EvalError> {
+        self.explain_with(cex, &Bindings::by_name(&self.0))
+    }
+
+    /// Explains why `cex` violates this spec with its arguments bound by `bindings`,
+    /// or returns `None` if it does not.
+    pub fn explain_with(
+        &self,
+        cex: &Counterexample,
+        bindings: &Bindings,
+    ) -> Result<Option<Explanation>, EvalError> {
+        explain(None, self, cex, bindings)
+    }
+}
+
+impl ElrondAssertion {
+    /// Explains why `cex` violates the assertion with its arguments bound by
+    /// `bindings`, or returns `None` if it does not.
+    pub fn explain_cex(
+        &self,
+        cex: &Counterexample,
+        bindings: &Bindings,
+    ) -> Result<Option<Explanation>, EvalError> {
+        explain(self.pre_spec.as_ref(), &self.post_spec, cex, bindings)
+    }
+
+    /// Explains each counterexample in `result`, usually with the
+    /// [`Bindings::post_call`] of the client's VC. Specs have nothing to explain, so
+    /// they give an empty list.
+    pub fn explain(
+        &self,
+        result: &ElrondResult,
+        bindings: &Bindings,
+    ) -> Result<Vec<Option<Explanation>>, EvalError> {
+        match result {
+            ElrondResult::Cex(cexs) => cexs
+                .iter()
+                .map(|cex| self.explain_cex(cex, bindings))
+                .collect(),
+            ElrondResult::Result(_) => Ok(vec![]),
+        }
+    }
+}
+
+/// Explains why `post` is false, with `pre` true, under some values of the
+/// arguments `bindings` leave unbound and of `post`'s quantified variables.
+fn explain(
+    pre: Option<&ElrondSpec>,
+    post: &ElrondSpec,
+    cex: &Counterexample,
+    bindings: &Bindings,
+) -> Result<Option<Explanation>, EvalError> {
+    let ElrondSpec(args, ElrondForallFormula(free_vars, body)) = post;
+    let env = bindings.env(cex)?;
+    let mut vars: Vec<FreeVar> = vec![];
+    for ElrondTpedvar(ty, name) in pre.iter().flat_map(|pre| &pre.0).chain(args) {
+        if !bindings.binds(name) && !vars.iter().any(|FreeVar(_, n)| n == name) {
+            vars.push(FreeVar(ty.clone(), name.clone()));
+        }
+    }
+    vars.extend(free_vars.iter().cloned());
+    let violated = |env: &Env| -> Result<bool, EvalError> {
+        let pre_holds = match pre {
+            Some(pre) => pre.1.eval(env)?,
+            None => true,
+        };
+        Ok(pre_holds && !body.eval(env)?)
+    };
+    let mut found = None;
+    for (env, assignment) in env.assignments(&vars) {
+        if violated(&env)? {
+            found = Some((env, assignment));
+            break;
+        }
+    }
+    let Some((env, quantified)) = found else {
+        return Ok(None);
+    };
+
+    let mut trace = vec![];
+    explain_false(body, &env, &mut trace)?;
+    let mut atoms = Atoms::default();
+    atoms.visit_pred(body);
+    let atoms = atoms
+        .0
+        .into_iter()
+        .map(|a| Ok((a.clone(), ElrondPred::Atom(a.clone()).eval(&env)?)))
+        .collect::<Result<_, EvalError>>()?;
+
+    Ok(Some(Explanation {
+        cex: cex.clone(),
+        quantified,
+        trace,
+        atoms,
+    }))
+}
+
+/// Descends from `pred`, which is false under `env`, towards the smallest false
+/// subformula that makes it so.
+fn explain_false(
+    pred: &ElrondPred,
+    env: &Env,
+    trace: &mut Vec<TraceStep>,
+) -> Result<(), EvalError> {
+    let mut step = |reason: String| {
+        trace.push(TraceStep {
+            formula: pred.clone(),
+            reason,
+        })
+    };
+    match pred {
+        ElrondPred::True => unreachable!("true is never false"),
+        ElrondPred::Atom(_) => step("the atom is false".to_string()),
+        ElrondPred::Not(_) => step("the negated formula is true".to_string()),
+        ElrondPred::Or(_) => step("every disjunct is false".to_string()),
+        ElrondPred::Iff(lhs, rhs) => step(format!(
+            "the sides differ: the left is {}, the right is {}",
+            lhs.eval(env)?,
+            rhs.eval(env)?
+        )),
+        ElrondPred::And(p_vec) => {
+            for (i, p) in p_vec.iter().enumerate() {
+                if !p.eval(env)? {
+                    step(format!("conjunct {} of {} is false", i + 1, p_vec.len()));
+                    return explain_false(p, env, trace);
+                }
+            }
+            unreachable!("a false conjunction has a false conjunct")
+        }
+        ElrondPred::Implies(_, rhs) => {
+            step("the premise holds but the conclusion is false".to_string());
+            return explain_false(rhs, env, trace);
+        }
+        ElrondPred::Ite(c, t, e) => {
+            let (taken, branch) = if c.eval(env)? {
+                (t, "then")
+            } else {
+                (e, "else")
+            };
+            step(format!("the {branch} branch is taken and is false"));
+            return explain_false(taken, env, trace);
+        }
+    }
+    Ok(())
+}
+
+/// The distinct atoms of a predicate, in order of appearance.
+#[derive(Default)]
+struct Atoms(Vec<ElrondSimpleExpr>);
+
+impl Visitor for Atoms {
+    fn visit_expr(&mut self, e: &ElrondSimpleExpr) {
+        if !self.0.contains(e) {
+            self.0.push(e.clone())
+        }
+    }
+}
+
+impl Display for Explanation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Counterexample:\n{}", self.cex)?;
+        if !self.quantified.is_empty() {
+            writeln!(f, "Falsified with:")?;
+            for (FreeVar(_, name), val) in &self.quantified {
+                writeln!(f, "\t{name} -> {val}")?;
+            }
+        }
+        writeln!(f, "Trace:")?;
+        for TraceStep { formula, reason } in &self.trace {
+            writeln!(f, "\t{formula}\n\t  {reason}")?;
+        }
+        writeln!(f, "Atoms:")?;
+        for (atom, val) in &self.atoms {
+            writeln!(f, "\t{atom} = {val}")?;
+        }
+        Ok(())
+    }
+}
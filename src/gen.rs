@@ -0,0 +1,203 @@
+//! Seeded random generation of `ElrondValue`s, for testing specs against sample inputs.
+
+use std::fmt::Display;
+
+use crate::{
+    eval::EvalError,
+    explain::Bindings,
+    ipc::{Counterexample, ElrondSpec, ElrondTpedvar, ElrondTree, ElrondType, ElrondValue},
+    predicates::ElrondDatatype,
+};
+
+/// Bounds on generated values.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct GenConfig {
+    /// Inclusive bounds on list lengths and tree sizes. Defaults to `0..=5`.
+    pub min_size: usize,
+    pub max_size: usize,
+    /// Inclusive bounds on ints and elements. Defaults to `0..=5`; small ranges make
+    /// repeated elements, and so interesting specs, likely.
+    pub min_int: i64,
+    pub max_int: i64,
+    /// What to generate for abstract types such as `Customstk.t`. Defaults to lists.
+    pub generic: ElrondDatatype,
+    /// How many candidates rejection sampling draws before giving up. Defaults to `1000`.
+    pub max_attempts: usize,
+}
+
+impl Default for GenConfig {
+    fn default() -> Self {
+        GenConfig {
+            min_size: 0,
+            max_size: 5,
+            min_int: 0,
+            max_int: 5,
+            generic: ElrondDatatype::List,
+            max_attempts: 1000,
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum GenError {
+    /// A type there is no generator for, such as a function type.
+    Unsupported(ElrondType),
+    /// No candidate satisfied the pre spec within `GenConfig::max_attempts`.
+    Exhausted(usize),
+    /// The pre spec could not be evaluated on a candidate.
+    Eval(EvalError),
+}
+
+impl Display for GenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GenError::Unsupported(ty) => write!(f, "cannot generate values of type {ty}"),
+            GenError::Exhausted(attempts) => {
+                write!(f, "no sample satisfied the pre spec in {attempts} attempts")
+            }
+            GenError::Eval(e) => write!(f, "could not evaluate the pre spec: {e}"),
+        }
+    }
+}
+
+impl From<EvalError> for GenError {
+    fn from(e: EvalError) -> Self {
+        GenError::Eval(e)
+    }
+}
+
+/// A value generator. The same seed and config always give the same values.
+#[derive(Debug, Clone)]
+pub struct Gen {
+    state: u64,
+    pub config: GenConfig,
+}
+
+impl Gen {
+    pub fn new(seed: u64, config: GenConfig) -> Self {
+        Gen {
+            state: seed,
+            config,
+        }
+    }
+
+    pub fn seeded(seed: u64) -> Self {
+        Gen::new(seed, GenConfig::default())
+    }
+
+    /// SplitMix64, which is small and stable across releases, unlike an external RNG.
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A uniform value in `lo..=hi`.
+    fn range(&mut self, lo: i64, hi: i64) -> i64 {
+        if hi <= lo {
+            return lo;
+        }
+        let span = hi.abs_diff(lo).wrapping_add(1);
+        let offset = if span == 0 {
+            self.next_u64()
+        } else {
+            self.next_u64() % span
+        };
+        lo.wrapping_add(offset as i64)
+    }
+
+    fn size(&mut self) -> usize {
+        let GenConfig {
+            min_size, max_size, ..
+        } = self.config;
+        self.range(min_size as i64, max_size as i64) as usize
+    }
+
+    pub fn int(&mut self) -> i64 {
+        self.range(self.config.min_int, self.config.max_int)
+    }
+
+    /// A uniform index into a slice of length `n`, which must not be empty.
+    pub fn index(&mut self, n: usize) -> usize {
+        assert!(n > 0, "no index into an empty slice");
+        self.range(0, n as i64 - 1) as usize
+    }
+
+    pub fn bool(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+
+    pub fn list(&mut self) -> Vec<i64> {
+        (0..self.size()).map(|_| self.int()).collect()
+    }
+
+    /// A tree with a uniformly chosen number of nodes, each split at random
+    /// between the left and right subtrees.
+    pub fn tree(&mut self) -> ElrondTree {
+        let size = self.size();
+        self.tree_of_size(size)
+    }
+
+    fn tree_of_size(&mut self, size: usize) -> ElrondTree {
+        if size == 0 {
+            return ElrondTree::Leaf;
+        }
+        let label = self.int();
+        let left = self.range(0, size as i64 - 1) as usize;
+        let l = self.tree_of_size(left);
+        let r = self.tree_of_size(size - 1 - left);
+        ElrondTree::node(label, l, r)
+    }
+
+    pub fn value(&mut self, ty: &ElrondType) -> Result<ElrondValue, GenError> {
+        match ty {
+            ElrondType::Int => Ok(ElrondValue::I(self.int())),
+            ElrondType::Bool => Ok(ElrondValue::B(self.bool())),
+            ElrondType::IntList => Ok(ElrondValue::L(self.list())),
+            ElrondType::IntTree => Ok(ElrondValue::T(self.tree())),
+            ElrondType::Generic(_) => {
+                let ty = self.config.generic.elrond_type();
+                self.value(&ty)
+            }
+            _ => Err(GenError::Unsupported(ty.clone())),
+        }
+    }
+
+    /// Values for every variable in `args`.
+    pub fn bindings(&mut self, args: &[ElrondTpedvar]) -> Result<Counterexample, GenError> {
+        args.iter()
+            .map(|ElrondTpedvar(ty, name)| Ok((self.value(ty)?, name.clone())))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Counterexample::new)
+    }
+
+    /// Values for every variable in `args` that satisfy `pre`, by rejection sampling.
+    /// Variables of `pre` missing from `args` are universally quantified.
+    pub fn sample(
+        &mut self,
+        args: &[ElrondTpedvar],
+        pre: Option<&ElrondSpec>,
+    ) -> Result<Counterexample, GenError> {
+        let bound = Bindings::by_name(args);
+        for _ in 0..self.config.max_attempts {
+            let candidate = self.bindings(args)?;
+            match pre {
+                Some(pre) if pre.explain_with(&candidate, &bound)?.is_some() => continue,
+                _ => return Ok(candidate),
+            }
+        }
+        Err(GenError::Exhausted(self.config.max_attempts))
+    }
+
+    /// `n` independent samples; see [`Gen::sample`].
+    pub fn samples(
+        &mut self,
+        n: usize,
+        args: &[ElrondTpedvar],
+        pre: Option<&ElrondSpec>,
+    ) -> Result<Vec<Counterexample>, GenError> {
+        (0..n).map(|_| self.sample(args, pre)).collect()
+    }
+}
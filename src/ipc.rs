@@ -1,19 +1,140 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
 
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
-use crate::ipc_assertion::AssertionType;
+use crate::{
+    lowering::AbstractTypes,
+    predicates::ElrondDatatype,
+    pretty::{PrettyConfig, PrettyStyle},
+};
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ArwenSetup {
-    pub sourcefile: String,
-    pub assertionfile: String,
+    pub sourcefile: ArwenInput,
+    pub assertionfile: ArwenInput,
     pub outputdir: String,
     pub client_name: String,
-    pub predicates: ElrondPredicates,
+    pub predicates: EnginePredicates,
+    pub mode: ElrondInferMode,
+    /// `None` keeps the engine's default of treating every library function as a hole.
+    pub holes: Option<ElrondHoles>,
+    pub options: InferenceOptions,
+}
+
+/// Knobs for the inference engine. Missing fields take their defaults when deserialized,
+/// so a sweep only needs to name the parameters it varies.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+#[serde(default)]
+pub struct InferenceOptions {
+    /// The bound passed to `SpecAbd.do_consistent`. Defaults to `1`, the value the
+    /// stub used to hardcode.
+    pub bound: u32,
+    /// The most universally quantified variables an inferred spec may use.
+    /// Defaults to `None`, the engine's own limit, which is the only value the stub
+    /// can pass on for now.
+    pub max_quantified_vars: Option<u32>,
+    /// How many samples the engine may draw per refinement. Defaults to `None`, the
+    /// engine's own budget, which is the only value the stub can pass on for now.
+    pub sample_budget: Option<u32>,
+    /// Seconds each inference phase may run for, enforced by the stub around each
+    /// engine call. A phase that runs out of time is answered with an
+    /// `ElrondMessage::Message` instead of a result. Defaults to `None`, no limit.
+    pub phase_timeout_secs: Option<u32>,
+    /// Seed for OCaml's `Random`, set before inference starts. Defaults to `0`.
+    /// A `u32` so that it always fits the stub's 63-bit `int`.
+    pub seed: u32,
+}
+
+impl Default for InferenceOptions {
+    fn default() -> Self {
+        InferenceOptions {
+            bound: 1,
+            max_quantified_vars: None,
+            sample_budget: None,
+            phase_timeout_secs: None,
+            seed: 0,
+        }
+    }
+}
+
+/// Which library functions Elrond infers specs for.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Default)]
+pub struct ElrondHoles {
+    /// Qualified names such as `Customstk.push`; empty means every function in the signature.
+    pub holes: Vec<String>,
+    /// Functions whose implementation is trusted, so they are never treated as holes.
+    pub trusted: Vec<String>,
+}
+
+/// An OCaml source or assertion file, either on a path the stub can open or
+/// sent over the pipe as text.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+pub enum ArwenInput {
+    File { path: String },
+    Inline { text: String },
+}
+
+impl ArwenInput {
+    pub fn file(path: impl Into<String>) -> ArwenInput {
+        ArwenInput::File { path: path.into() }
+    }
+
+    pub fn inline(text: impl Into<String>) -> ArwenInput {
+        ArwenInput::Inline { text: text.into() }
+    }
+
+    /// The text of the input, reading it from disk if needed.
+    pub fn read(&self) -> Result<String, String> {
+        match self {
+            ArwenInput::File { path } => {
+                std::fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))
+            }
+            ArwenInput::Inline { text } => Ok(text.clone()),
+        }
+    }
+}
+
+impl From<&ElrondAssertion> for ArwenInput {
+    fn from(assertion: &ElrondAssertion) -> Self {
+        ArwenInput::inline(assertion.to_string())
+    }
+}
+
+impl Display for ArwenInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArwenInput::File { path } => write!(f, "{path}"),
+            ArwenInput::Inline { text } => write!(f, "{text}"),
+        }
+    }
+}
+
+/// Which of the engine's inference procedures to run.
+/// See inference/specAbduction.ml -> do_consistent / do_weakening
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum ElrondInferMode {
+    /// Find specs for the holes that are consistent with the client's VC.
+    #[default]
+    Consistent,
+    /// Weaken the consistent specs a previous run saved.
+    /// See `ArwenSetup::consistent_specs` for where they are read from.
+    Weakening,
+    /// Consistent inference followed by weakening of its result.
+    Full,
+}
+
+impl Display for ElrondInferMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ElrondInferMode::Consistent => write!(f, "Consistent"),
+            ElrondInferMode::Weakening => write!(f, "Weakening"),
+            ElrondInferMode::Full => write!(f, "Full"),
+        }
+    }
 }
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Serialize, Deserialize, Debug)]
 pub enum ArwenMessage {
     Start,
@@ -25,21 +146,22 @@ pub enum ArwenMessage {
 #[derive(Serialize, Deserialize, PartialEq, Debug)]
 pub enum ElrondMessage {
     Message(String),
-    Result(ElrondResult),
+    Result(ElrondInferMode, ElrondResult),
 }
 
 impl Display for ElrondMessage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             ElrondMessage::Message(msg) => write!(f, "Message: {}", msg),
-            ElrondMessage::Result(result) => write!(f, "Result:\n{}", result),
+            ElrondMessage::Result(mode, result) => write!(f, "{mode} Result:\n{}", result),
         }
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub enum ElrondValue {
     L(Vec<i64>),
+    T(ElrondTree),
     I(i64),
     B(bool),
     NotADt,
@@ -49,6 +171,7 @@ impl Display for ElrondValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             ElrondValue::L(l) => write!(f, "[{}]", l.iter().join(", ")),
+            ElrondValue::T(t) => write!(f, "{t}"),
             ElrondValue::I(i) => write!(f, "{}", i),
             ElrondValue::B(b) => write!(f, "{}", b),
             ElrondValue::NotADt => write!(f, "NotADt"),
@@ -56,7 +179,33 @@ impl Display for ElrondValue {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// See utils/tree.ml -> Tree
+#[derive(Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone)]
+pub enum ElrondTree {
+    Leaf,
+    Node(i64, Box<ElrondTree>, Box<ElrondTree>),
+}
+
+impl ElrondTree {
+    pub fn node(label: i64, left: ElrondTree, right: ElrondTree) -> Self {
+        ElrondTree::Node(label, Box::new(left), Box::new(right))
+    }
+
+    pub fn singleton(label: i64) -> Self {
+        ElrondTree::node(label, ElrondTree::Leaf, ElrondTree::Leaf)
+    }
+}
+
+impl Display for ElrondTree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ElrondTree::Leaf => write!(f, "Leaf"),
+            ElrondTree::Node(label, left, right) => write!(f, "Node({label}, {left}, {right})"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub enum ElrondLiteral {
     Int(i64),
     Bool(bool),
@@ -73,7 +222,7 @@ impl Display for ElrondLiteral {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone)]
 pub enum ElrondType {
     Bool,
     Int,
@@ -104,7 +253,7 @@ impl Display for ElrondType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub enum ElrondSimpleExpr {
     Literal(ElrondType, ElrondLiteral),
     Var(ElrondType, String),
@@ -117,7 +266,10 @@ impl Display for ElrondSimpleExpr {
         match self {
             ElrondSimpleExpr::Literal(_ty, lit) => write!(f, "{lit}"),
             ElrondSimpleExpr::Var(_ty, name) => write!(f, "{name}"),
-            ElrondSimpleExpr::Op(_ty, op, args) if op == "<=" || op == ">=" => {
+            ElrondSimpleExpr::Op(_ty, op, args)
+                if args.len() == 2
+                    && matches!(op.as_str(), "==" | "!=" | "<=" | ">=" | "<" | ">") =>
+            {
                 write!(f, "({} {} {})", args[0], op, args[1])
             }
             ElrondSimpleExpr::Op(_ty, op, args) => {
@@ -130,7 +282,7 @@ impl Display for ElrondSimpleExpr {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub enum ElrondPred {
     True,
     Atom(ElrondSimpleExpr),
@@ -159,24 +311,11 @@ impl ElrondPred {
 
 impl Display for ElrondPred {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            ElrondPred::True => write!(f, "true"),
-            ElrondPred::Atom(expr) => write!(f, "{expr}"),
-            ElrondPred::Implies(lhs, rhs) => write!(f, "implies ({lhs}) ({rhs})"),
-            ElrondPred::Ite(cond, lhs, rhs) => write!(f, "(if {cond} then {lhs} else {rhs})"),
-            ElrondPred::Not(pred) => write!(f, "(!{pred})"),
-            ElrondPred::And(preds) => write!(
-                f,
-                "({})",
-                preds.iter().map(|p| format!("{}", p)).join(" && ")
-            ),
-            ElrondPred::Or(preds) => write!(
-                f,
-                "({})",
-                preds.iter().map(|p| format!("{}", p)).join(" || ")
-            ),
-            ElrondPred::Iff(lhs, rhs) => write!(f, "iff ({lhs}) ({rhs})"),
-        }
+        write!(
+            f,
+            "{}",
+            self.pretty(&PrettyConfig::flat(PrettyStyle::OCaml))
+        )
     }
 }
 
@@ -189,7 +328,7 @@ pub enum ElrondOp {
     Gt,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct FreeVar(pub ElrondType, pub String);
 
 impl Display for FreeVar {
@@ -199,8 +338,8 @@ impl Display for FreeVar {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
-pub struct ElrondTpedvar(pub AssertionType, pub String);
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct ElrondTpedvar(pub ElrondType, pub String);
 
 impl Display for ElrondTpedvar {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -209,7 +348,7 @@ impl Display for ElrondTpedvar {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct ElrondForallFormula(pub Vec<FreeVar>, pub ElrondPred);
 
 impl Display for ElrondForallFormula {
@@ -227,7 +366,7 @@ impl Display for ElrondForallFormula {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct ElrondSpec(pub Vec<ElrondTpedvar>, pub ElrondForallFormula);
 
 impl Display for ElrondSpec {
@@ -245,9 +384,94 @@ impl Display for ElrondSpec {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// An assignment of values to the client's variables under which the assertion fails.
+/// On the wire this is the engine's list of `(value, name)` pairs; bindings are kept
+/// sorted by name so that lookups, renderings and comparisons are deterministic.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+#[serde(
+    from = "Vec<(ElrondValue, String)>",
+    into = "Vec<(ElrondValue, String)>"
+)]
+pub struct Counterexample {
+    bindings: Vec<(ElrondValue, String)>,
+}
+
+impl Counterexample {
+    pub fn new(mut bindings: Vec<(ElrondValue, String)>) -> Self {
+        bindings.sort_by(|(_, n1), (_, n2)| n1.cmp(n2));
+        Counterexample { bindings }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ElrondValue> {
+        self.bindings
+            .binary_search_by(|(_, n)| n.as_str().cmp(name))
+            .ok()
+            .map(|i| &self.bindings[i].0)
+    }
+
+    /// The bindings as `(name, value)`, ordered by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ElrondValue)> {
+        self.bindings.iter().map(|(v, n)| (n.as_str(), v))
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.bindings.iter().map(|(_, n)| n.as_str())
+    }
+
+    pub fn len(&self) -> usize {
+        self.bindings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bindings.is_empty()
+    }
+
+    /// Binds `name` to `value`, returning the value it replaces.
+    pub fn insert(&mut self, name: &str, value: ElrondValue) -> Option<ElrondValue> {
+        match self
+            .bindings
+            .binary_search_by(|(_, n)| n.as_str().cmp(name))
+        {
+            Ok(i) => Some(std::mem::replace(&mut self.bindings[i].0, value)),
+            Err(i) => {
+                self.bindings.insert(i, (value, name.to_string()));
+                None
+            }
+        }
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<ElrondValue> {
+        self.bindings
+            .binary_search_by(|(_, n)| n.as_str().cmp(name))
+            .ok()
+            .map(|i| self.bindings.remove(i).0)
+    }
+}
+
+impl From<Vec<(ElrondValue, String)>> for Counterexample {
+    fn from(bindings: Vec<(ElrondValue, String)>) -> Self {
+        Counterexample::new(bindings)
+    }
+}
+
+impl From<Counterexample> for Vec<(ElrondValue, String)> {
+    fn from(cex: Counterexample) -> Self {
+        cex.bindings
+    }
+}
+
+impl Display for Counterexample {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (name, val) in self.iter() {
+            writeln!(f, "\t{name} -> {val}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub enum ElrondResult {
-    Cex(Vec<Vec<(ElrondValue, String)>>),
+    Cex(Vec<Counterexample>),
     Result(Vec<(ElrondSpec, String)>),
 }
 
@@ -256,10 +480,7 @@ impl Display for ElrondResult {
         match self {
             ElrondResult::Cex(c) => {
                 for (i, cex) in c.iter().enumerate() {
-                    writeln!(f, "Cex {i}:")?;
-                    for (val, name) in cex {
-                        writeln!(f, "\t{name} -> {val}")?;
-                    }
+                    write!(f, "Cex {i}:\n{cex}")?;
                 }
                 Ok(())
             }
@@ -275,41 +496,158 @@ impl Display for ElrondResult {
 }
 
 /// See translate/translate.ml -> TenvEngine -> known_preds
+///
+/// Each predicate has a surface name used in assertion files (`mem`), and an engine
+/// name for each datatype it applies to (`list_member`, `tree_member`); see
+/// [`ElrondKnownPredicate::engine_name`]. It serializes under its surface name, as
+/// it does not know its datatype; [`EnginePredicates`] puts engine names on the
+/// wire. Deserialization accepts any of the spellings.
 #[allow(non_camel_case_types)]
-#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone, Copy)]
 pub enum ElrondKnownPredicate {
+    #[serde(rename = "len", alias = "length", alias = "list_length")]
     length,
+    #[serde(rename = "sorted", alias = "list_sorted")]
     sorted,
+    #[serde(
+        rename = "mem",
+        alias = "member",
+        alias = "list_member",
+        alias = "tree_member"
+    )]
     member,
+    #[serde(rename = "hd", alias = "head", alias = "list_head")]
     head,
+    #[serde(rename = "ord", alias = "order", alias = "list_order")]
     order,
+    #[serde(rename = "once", alias = "list_once", alias = "tree_once")]
     once,
+    #[serde(rename = "left", alias = "tree_left")]
     left,
+    #[serde(rename = "right", alias = "tree_right")]
     right,
+    #[serde(rename = "para", alias = "tree_parallel")]
     para,
+    #[serde(rename = "ance", alias = "tree_ancestor")]
     ance,
+    #[serde(rename = "root", alias = "tree_root")]
     root,
 }
 
-impl Display for ElrondKnownPredicate {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ElrondKnownPredicate {
+    pub const ALL: [ElrondKnownPredicate; 11] = [
+        ElrondKnownPredicate::length,
+        ElrondKnownPredicate::sorted,
+        ElrondKnownPredicate::member,
+        ElrondKnownPredicate::head,
+        ElrondKnownPredicate::order,
+        ElrondKnownPredicate::once,
+        ElrondKnownPredicate::left,
+        ElrondKnownPredicate::right,
+        ElrondKnownPredicate::para,
+        ElrondKnownPredicate::ance,
+        ElrondKnownPredicate::root,
+    ];
+
+    /// The canonical name table: `(variant, surface)` spellings.
+    pub fn names(&self) -> (&'static str, &'static str) {
         match self {
-            ElrondKnownPredicate::length => write!(f, "\"len\""),
-            ElrondKnownPredicate::member => write!(f, "\"mem\""),
-            ElrondKnownPredicate::head => write!(f, "\"hd\""),
-            ElrondKnownPredicate::order => write!(f, "\"ord\""),
-            ElrondKnownPredicate::left => write!(f, "\"left\""),
-            ElrondKnownPredicate::right => write!(f, "\"right\""),
-            ElrondKnownPredicate::sorted => write!(f, "\"sorted\""),
-            ElrondKnownPredicate::once => write!(f, "\"once\""),
-            ElrondKnownPredicate::para => write!(f, "\"para\""),
-            ElrondKnownPredicate::ance => write!(f, "\"ance\""),
-            ElrondKnownPredicate::root => write!(f, "\"root\""),
+            ElrondKnownPredicate::length => ("length", "len"),
+            ElrondKnownPredicate::sorted => ("sorted", "sorted"),
+            ElrondKnownPredicate::member => ("member", "mem"),
+            ElrondKnownPredicate::head => ("head", "hd"),
+            ElrondKnownPredicate::order => ("order", "ord"),
+            ElrondKnownPredicate::once => ("once", "once"),
+            ElrondKnownPredicate::left => ("left", "left"),
+            ElrondKnownPredicate::right => ("right", "right"),
+            ElrondKnownPredicate::para => ("para", "para"),
+            ElrondKnownPredicate::ance => ("ance", "ance"),
+            ElrondKnownPredicate::root => ("root", "root"),
         }
     }
+
+    /// The name used in assertion files, e.g. `mem`.
+    pub fn surface_name(&self) -> &'static str {
+        self.names().1
+    }
+
+    /// The name the engine uses for this predicate on `datatype`, e.g. `list_member`
+    /// or `tree_member`. `None` if the predicate does not apply to `datatype`.
+    ///
+    /// The engine output checked in under `_customstk_out` confirms only the list
+    /// names of `member`, `head` and `order`.
+    pub fn engine_name(&self, datatype: ElrondDatatype) -> Option<&'static str> {
+        use ElrondDatatype::{List, Tree};
+        let name = match (self, datatype) {
+            (ElrondKnownPredicate::length, List) => "list_length",
+            (ElrondKnownPredicate::sorted, List) => "list_sorted",
+            (ElrondKnownPredicate::member, List) => "list_member",
+            (ElrondKnownPredicate::member, Tree) => "tree_member",
+            (ElrondKnownPredicate::head, List) => "list_head",
+            (ElrondKnownPredicate::order, List) => "list_order",
+            (ElrondKnownPredicate::once, List) => "list_once",
+            (ElrondKnownPredicate::once, Tree) => "tree_once",
+            (ElrondKnownPredicate::left, Tree) => "tree_left",
+            (ElrondKnownPredicate::right, Tree) => "tree_right",
+            (ElrondKnownPredicate::para, Tree) => "tree_parallel",
+            (ElrondKnownPredicate::ance, Tree) => "tree_ancestor",
+            (ElrondKnownPredicate::root, Tree) => "tree_root",
+            _ => return None,
+        };
+        Some(name)
+    }
+
+    /// The predicate and datatype an engine name such as `tree_member` stands for.
+    pub fn from_engine_name(name: &str) -> Option<(ElrondKnownPredicate, ElrondDatatype)> {
+        ElrondKnownPredicate::ALL
+            .into_iter()
+            .cartesian_product([ElrondDatatype::List, ElrondDatatype::Tree])
+            .find(|(p, datatype)| p.engine_name(*datatype) == Some(name))
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct UnknownPredicate(pub String);
+
+impl Display for UnknownPredicate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown predicate `{}`", self.0)
+    }
+}
+
+impl std::error::Error for UnknownPredicate {}
+
+impl FromStr for ElrondKnownPredicate {
+    type Err = UnknownPredicate;
+
+    /// Accepts the variant or surface spelling, or the engine name for any datatype.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ElrondKnownPredicate::ALL
+            .into_iter()
+            .find(|p| {
+                let (variant, surface) = p.names();
+                s == variant || s == surface
+            })
+            .or_else(|| ElrondKnownPredicate::from_engine_name(s).map(|(p, _)| p))
+            .ok_or_else(|| UnknownPredicate(s.to_string()))
+    }
+}
+
+impl TryFrom<&str> for ElrondKnownPredicate {
+    type Error = UnknownPredicate;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl Display for ElrondKnownPredicate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.surface_name())
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct ElrondPredicates(pub Vec<ElrondKnownPredicate>);
 
 impl Display for ElrondPredicates {
@@ -317,16 +655,85 @@ impl Display for ElrondPredicates {
         write!(
             f,
             "let preds = [| {} |]",
-            self.0.iter().map(ToString::to_string).join("; ")
+            self.0.iter().map(|p| format!("\"{p}\"")).join("; ")
         )
     }
 }
 
-#[derive(Debug)]
+/// The predicates a setup lets the engine use, applied to `datatype`. Serializes as
+/// the list of their engine names, e.g. `["tree_member"]`.
+/// See translate/translate.ml -> TenvEngine -> all_preds
+#[derive(PartialEq, Debug, Clone)]
+pub struct EnginePredicates {
+    pub datatype: ElrondDatatype,
+    pub predicates: ElrondPredicates,
+}
+
+impl EnginePredicates {
+    /// The engine names of the predicates, or the first predicate that does not
+    /// apply to the datatype.
+    pub fn names(&self) -> Result<Vec<&'static str>, ElrondKnownPredicate> {
+        self.predicates
+            .0
+            .iter()
+            .map(|p| p.engine_name(self.datatype).ok_or(*p))
+            .collect()
+    }
+}
+
+impl Serialize for EnginePredicates {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let names = self.names().map_err(|p| {
+            serde::ser::Error::custom(format!(
+                "{} does not apply to a {}",
+                p.surface_name(),
+                self.datatype
+            ))
+        })?;
+        names.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for EnginePredicates {
+    /// Takes the datatype from the engine names, which must agree on it.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let names = Vec::<String>::deserialize(deserializer)?;
+        let (predicates, datatypes): (Vec<_>, Vec<_>) = names
+            .iter()
+            .map(|name| {
+                ElrondKnownPredicate::from_engine_name(name).ok_or_else(|| {
+                    serde::de::Error::custom(format!("unknown engine predicate `{name}`"))
+                })
+            })
+            .collect::<Result<Vec<_>, D::Error>>()?
+            .into_iter()
+            .unzip();
+        let datatype = match datatypes.into_iter().unique().collect::<Vec<_>>()[..] {
+            [] => ElrondDatatype::List,
+            [datatype] => datatype,
+            _ => {
+                return Err(serde::de::Error::custom(
+                    "predicates for both lists and trees",
+                ))
+            }
+        };
+        Ok(EnginePredicates {
+            datatype,
+            predicates: ElrondPredicates(predicates),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct ElrondAssertion {
     pub preds: ElrondPredicates,
     pub pre_spec: Option<ElrondSpec>,
     pub post_spec: ElrondSpec,
+    /// The abstract types, such as `Customstk.t`, behind the datatypes in the specs.
+    /// Written assertions use these names, as the engine only parses the types the
+    /// source's signature declares.
+    #[serde(default)]
+    pub types: AbstractTypes,
 }
 
 impl Display for ElrondAssertion {
@@ -335,14 +742,15 @@ impl Display for ElrondAssertion {
             preds,
             pre_spec,
             post_spec,
+            types,
         } = self;
         writeln!(f, "{preds}",)?;
         if let Some(pre_spec) = pre_spec {
-            pre_spec.spec_writer_for_file(f, SpecKind::Pre)?;
+            pre_spec.spec_writer_for_file(f, SpecKind::Pre, types)?;
         }
         writeln!(f)?;
 
-        post_spec.spec_writer_for_file(f, SpecKind::Post)?;
+        post_spec.spec_writer_for_file(f, SpecKind::Post, types)?;
 
         Ok(())
     }
@@ -367,14 +775,17 @@ impl ElrondSpec {
         &self,
         f: &mut std::fmt::Formatter<'_>,
         kind: SpecKind,
+        types: &AbstractTypes,
     ) -> std::fmt::Result {
-        let ElrondSpec(vars, formula) = self;
+        // Assertion files have no quantifiers, so free variables are written as arguments
+        let ElrondSpec(vars, ElrondForallFormula(free_vars, formula)) = self;
         write!(
             f,
             "let {kind} {} =\n  {}\n",
             vars.iter()
-                .map(ToString::to_string)
-                .map(|s| "(".to_string() + &s + ")")
+                .map(|ElrondTpedvar(ty, name)| (ty, name))
+                .chain(free_vars.iter().map(|FreeVar(ty, name)| (ty, name)))
+                .map(|(ty, name)| format!("({name} : {})", types.type_name(ty)))
                 .join(" "),
             formula
         )
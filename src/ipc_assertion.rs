@@ -3,6 +3,11 @@ use std::fmt::Display;
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
+use crate::pretty::{PrettyConfig, PrettyStyle};
+
+/// Assertion files share the canonical predicate table with the Elrond side.
+pub use crate::ipc::ElrondKnownPredicate as Pred;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub enum AssertionType {
     Bool,
@@ -20,7 +25,7 @@ impl Display for AssertionType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub enum Assertion {
     True,
     Predicate(Pred, Vec<String>),
@@ -48,29 +53,15 @@ pub enum Assertion {
 
 impl Display for Assertion {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Assertion::Implies(lhs, rhs) => write!(f, "implies ({lhs}) ({rhs})"),
-            Assertion::Not(pred) => write!(f, "!{pred}"),
-            Assertion::And(preds) => {
-                write!(f, "{}", preds.iter().map(|p| format!("{}", p)).join(" && "))
-            }
-            Assertion::Or(preds) => {
-                write!(f, "{}", preds.iter().map(|p| format!("{}", p)).join(" || "))
-            }
-            Assertion::Iff(lhs, rhs) => write!(f, "iff ({lhs}) ({rhs})"),
-            Assertion::Predicate(p1, v) => write!(
-                f,
-                "{p1} {v}",
-                p1 = p1,
-                v = v.iter().map(ToString::to_string).join(" ")
-            ),
-            Assertion::Op(o, v1, v2) => write!(f, "{v1} {o} {v2}",),
-            Assertion::True => write!(f, "true"),
-        }
+        write!(
+            f,
+            "{}",
+            self.pretty(&PrettyConfig::flat(PrettyStyle::OCaml))
+        )
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub enum AssertionOp {
     Eq,
     Le,
@@ -83,7 +74,7 @@ pub enum AssertionOp {
 impl Display for AssertionOp {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            AssertionOp::Eq => write!(f, "="),
+            AssertionOp::Eq => write!(f, "=="),
             AssertionOp::Le => write!(f, "<="),
             AssertionOp::Ge => write!(f, ">="),
             AssertionOp::Ne => write!(f, "!="),
@@ -93,7 +84,7 @@ impl Display for AssertionOp {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct FreeVar(pub AssertionType, pub String);
 
 impl Display for FreeVar {
@@ -103,7 +94,7 @@ impl Display for FreeVar {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct Tpedvar(pub AssertionType, pub String);
 
 impl Display for Tpedvar {
@@ -113,7 +104,7 @@ impl Display for Tpedvar {
     }
 }
 
-/* #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/* #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct ForallFormula(pub Vec<FreeVar>, pub Assertion);
 
 impl Display for ForallFormula {
@@ -131,7 +122,7 @@ impl Display for ForallFormula {
     }
 } */
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct Spec(pub Vec<Tpedvar>, pub Assertion);
 
 impl Display for Spec {
@@ -149,42 +140,7 @@ impl Display for Spec {
     }
 }
 
-/// See translate/translate.ml -> TenvEngine -> known_preds
-#[allow(non_camel_case_types)]
-#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
-pub enum Pred {
-    length,
-    sorted,
-    member,
-    head,
-    order,
-    once,
-    left,
-    right,
-    para,
-    ance,
-    root,
-}
-
-impl Display for Pred {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Pred::length => write!(f, "len"),
-            Pred::member => write!(f, "mem"),
-            Pred::head => write!(f, "hd"),
-            Pred::order => write!(f, "ord"),
-            Pred::left => write!(f, "left"),
-            Pred::right => write!(f, "right"),
-            Pred::sorted => write!(f, "sorted"),
-            Pred::once => write!(f, "once"),
-            Pred::para => write!(f, "para"),
-            Pred::ance => write!(f, "ance"),
-            Pred::root => write!(f, "root"),
-        }
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct AssertionPredicates(pub Vec<Pred>);
 
 impl Display for AssertionPredicates {
@@ -192,12 +148,12 @@ impl Display for AssertionPredicates {
         write!(
             f,
             "let preds = [| {} |]",
-            self.0.iter().map(ToString::to_string).join("; ")
+            self.0.iter().map(|p| format!("\"{p}\"")).join("; ")
         )
     }
 }
 
-#[derive(Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct AssertionFile {
     pub preds: AssertionPredicates,
     pub pre_spec: Option<Spec>,
@@ -205,50 +161,13 @@ pub struct AssertionFile {
 }
 
 impl Display for AssertionFile {
+    /// Writes the file in the syntax `AssertionFileParser` reads, with each spec
+    /// on one line.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let AssertionFile {
-            preds,
-            pre_spec,
-            post_spec,
-        } = self;
-        writeln!(f, "{preds}",)?;
-        if let Some(pre_spec) = pre_spec {
-            pre_spec.spec_writer_for_file(f, SpecKind::Pre)?;
-        }
-        writeln!(f)?;
-
-        post_spec.spec_writer_for_file(f, SpecKind::Post)?;
-
-        Ok(())
-    }
-}
-
-enum SpecKind {
-    Pre,
-    Post,
-}
-
-impl Display for SpecKind {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            SpecKind::Pre => write!(f, "pre"),
-            SpecKind::Post => write!(f, "post"),
-        }
-    }
-}
-
-impl Spec {
-    fn spec_writer_for_file(
-        &self,
-        f: &mut std::fmt::Formatter<'_>,
-        kind: SpecKind,
-    ) -> std::fmt::Result {
-        let Spec(vars, formula) = self;
-        write!(
-            f,
-            "let {kind} {} =\n  {}\n",
-            vars.iter().map(ToString::to_string).join(" "),
-            formula
-        )
+        let config = PrettyConfig {
+            width: usize::MAX,
+            ..PrettyConfig::default()
+        };
+        write!(f, "{}", self.pretty(&config))
     }
 }
@@ -1,6 +1,27 @@
+#[allow(clippy::all)]
 pub mod assertion_parser;
+pub mod cex;
+pub mod diff;
+pub mod entail;
+pub mod eval;
+pub mod explain;
+pub mod gen;
 pub mod ipc_assertion;
 pub mod ipc;
+pub mod lowering;
+pub mod predicates;
+pub mod pretty;
+#[allow(clippy::all)]
+pub mod program_parser;
+pub mod setup;
+pub mod shrink;
+pub mod simplify;
+pub mod smt;
+pub mod subst;
+pub mod typecheck;
+pub mod usage;
+pub mod vc;
+pub mod visit;
 
 use std::{
     process::Command,
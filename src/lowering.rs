@@ -0,0 +1,384 @@
+//! Conversions between the assertion-file AST in [`crate::ipc_assertion`] and the
+//! typed Elrond AST in [`crate::ipc`].
+
+use std::{collections::HashMap, fmt::Display};
+
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ipc::{
+        ElrondAssertion, ElrondForallFormula, ElrondKnownPredicate, ElrondPred, ElrondPredicates,
+        ElrondSimpleExpr, ElrondSpec, ElrondTpedvar, ElrondType,
+    },
+    ipc_assertion::{
+        Assertion, AssertionFile, AssertionOp, AssertionPredicates, AssertionType, Spec, Tpedvar,
+    },
+    predicates::ElrondDatatype,
+};
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum LoweringError {
+    /// A variable that is not one of the spec's arguments.
+    UnboundVariable(String),
+    /// A type with no assertion-file spelling, such as a tuple.
+    UnsupportedType(ElrondType),
+    /// An expression assertion files cannot express, such as a literal.
+    UnsupportedExpr(String),
+    /// An abstract type such as `Customstk.t` that is not named in the
+    /// [`AbstractTypes`] and whose uses fit `candidates`, which is not exactly one
+    /// datatype.
+    UnresolvedType {
+        ty: String,
+        candidates: Vec<ElrondDatatype>,
+    },
+}
+
+impl Display for LoweringError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoweringError::UnboundVariable(name) => write!(f, "unbound variable {name}"),
+            LoweringError::UnsupportedType(ty) => {
+                write!(f, "type {ty} cannot be written in an assertion file")
+            }
+            LoweringError::UnsupportedExpr(e) => {
+                write!(f, "{e} cannot be written in an assertion file")
+            }
+            LoweringError::UnresolvedType { ty, candidates } if candidates.is_empty() => {
+                write!(f, "no datatype fits every use of {ty}")
+            }
+            LoweringError::UnresolvedType { ty, candidates } => write!(
+                f,
+                "{ty} could be a {}; name it in the abstract types",
+                candidates.iter().join(" or a ")
+            ),
+        }
+    }
+}
+
+/// The datatypes behind abstract types such as `Customstk.t`. The engine only
+/// decodes concrete datatypes, so lowering gives every abstract type the datatype
+/// named here, or else the one datatype all the predicates applied to its values
+/// accept, or a list if there are none. Lifting writes a datatype as the first abstract type naming it, or as
+/// `list` or `tree`.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Default)]
+pub struct AbstractTypes(pub Vec<(String, ElrondDatatype)>);
+
+impl AbstractTypes {
+    pub fn get(&self, ty: &str) -> Option<ElrondDatatype> {
+        self.0
+            .iter()
+            .find_map(|(name, dt)| (name == ty).then_some(*dt))
+            .or_else(|| {
+                [ElrondDatatype::List, ElrondDatatype::Tree]
+                    .into_iter()
+                    .find(|dt| dt.to_string() == ty)
+            })
+    }
+
+    pub fn name(&self, datatype: ElrondDatatype) -> String {
+        self.0
+            .iter()
+            .find_map(|(name, dt)| (*dt == datatype).then(|| name.clone()))
+            .unwrap_or_else(|| datatype.to_string())
+    }
+
+    /// The type as an assertion file writes it, with datatypes under their names here.
+    pub fn type_name(&self, ty: &ElrondType) -> String {
+        match ElrondDatatype::from_type(ty) {
+            Some(dt) => self.name(dt),
+            None => ty.to_string(),
+        }
+    }
+
+    /// Extends these with a datatype for every other abstract type in `file`.
+    pub fn resolve(&self, file: &AssertionFile) -> Result<AbstractTypes, LoweringError> {
+        let mut uses: Vec<(&str, Option<Vec<ElrondDatatype>>)> = vec![];
+        for Spec(args, assertion) in file.pre_spec.iter().chain([&file.post_spec]) {
+            for Tpedvar(ty, _) in args {
+                match ty {
+                    AssertionType::Generic(name)
+                        if self.get(name).is_none() && !uses.iter().any(|(n, _)| n == name) =>
+                    {
+                        uses.push((name, None))
+                    }
+                    _ => {}
+                }
+            }
+            narrow(assertion, args, &mut uses);
+        }
+
+        let mut resolved = self.clone();
+        for (ty, candidates) in uses {
+            let candidates = candidates.unwrap_or_else(|| vec![ElrondDatatype::List]);
+            match candidates.as_slice() {
+                [dt] => resolved.0.push((ty.to_string(), *dt)),
+                _ => {
+                    return Err(LoweringError::UnresolvedType {
+                        ty: ty.to_string(),
+                        candidates,
+                    })
+                }
+            }
+        }
+        Ok(resolved)
+    }
+
+    fn lower(&self, ty: &AssertionType) -> Result<ElrondType, LoweringError> {
+        match ty {
+            AssertionType::Bool => Ok(ElrondType::Bool),
+            AssertionType::Int => Ok(ElrondType::Int),
+            AssertionType::Generic(s) => self.get(s).map(|dt| dt.elrond_type()).ok_or_else(|| {
+                LoweringError::UnresolvedType {
+                    ty: s.clone(),
+                    candidates: vec![ElrondDatatype::List, ElrondDatatype::Tree],
+                }
+            }),
+        }
+    }
+
+    fn lift(&self, ty: &ElrondType) -> Result<AssertionType, LoweringError> {
+        match ty {
+            ElrondType::Bool => Ok(AssertionType::Bool),
+            ElrondType::Int => Ok(AssertionType::Int),
+            ElrondType::Generic(s) => Ok(AssertionType::Generic(s.clone())),
+            _ => ElrondDatatype::from_type(ty)
+                .map(|dt| AssertionType::Generic(self.name(dt)))
+                .ok_or_else(|| LoweringError::UnsupportedType(ty.clone())),
+        }
+    }
+}
+
+/// Keeps only the datatypes that every predicate applied to a value of an abstract
+/// type accepts. Types no predicate is applied to stay `None`.
+fn narrow(
+    assertion: &Assertion,
+    args: &[Tpedvar],
+    uses: &mut [(&str, Option<Vec<ElrondDatatype>>)],
+) {
+    match assertion {
+        Assertion::True | Assertion::Op(..) => {}
+        Assertion::Predicate(p, vars) => {
+            let ty = vars
+                .first()
+                .and_then(|v| args.iter().find(|Tpedvar(_, n)| n == v));
+            if let Some(Tpedvar(AssertionType::Generic(name), _)) = ty {
+                if let Some((_, candidates)) = uses.iter_mut().find(|(n, _)| n == name) {
+                    candidates
+                        .get_or_insert_with(|| p.signature().datatypes.to_vec())
+                        .retain(|dt| p.signature().applies_to(*dt));
+                }
+            }
+        }
+        Assertion::Implies(a1, a2) | Assertion::Iff(a1, a2) => {
+            narrow(a1, args, uses);
+            narrow(a2, args, uses);
+        }
+        Assertion::Not(a) => narrow(a, args, uses),
+        Assertion::And(a_vec) | Assertion::Or(a_vec) => {
+            a_vec.iter().for_each(|a| narrow(a, args, uses))
+        }
+    }
+}
+
+impl AssertionOp {
+    /// The operator as it appears in an `ElrondSimpleExpr::Op`.
+    pub fn elrond_name(&self) -> &'static str {
+        match self {
+            AssertionOp::Eq => "==",
+            AssertionOp::Le => "<=",
+            AssertionOp::Ge => ">=",
+            AssertionOp::Ne => "!=",
+            AssertionOp::Lt => "<",
+            AssertionOp::Gt => ">",
+        }
+    }
+
+    pub fn from_elrond_name(name: &str) -> Option<AssertionOp> {
+        match name {
+            "==" => Some(AssertionOp::Eq),
+            "<=" => Some(AssertionOp::Le),
+            ">=" => Some(AssertionOp::Ge),
+            "!=" => Some(AssertionOp::Ne),
+            "<" => Some(AssertionOp::Lt),
+            ">" => Some(AssertionOp::Gt),
+            _ => None,
+        }
+    }
+}
+
+/* Lowering: AssertionFile -> ElrondAssertion */
+
+impl TryFrom<&AssertionFile> for ElrondAssertion {
+    type Error = LoweringError;
+
+    /// Lowers with every abstract type resolved from the predicates applied to it.
+    fn try_from(file: &AssertionFile) -> Result<Self, Self::Error> {
+        lower_file(file, &AbstractTypes::default())
+    }
+}
+
+pub fn lower_file(
+    file: &AssertionFile,
+    types: &AbstractTypes,
+) -> Result<ElrondAssertion, LoweringError> {
+    let types = types.resolve(file)?;
+    Ok(ElrondAssertion {
+        preds: ElrondPredicates(file.preds.0.clone()),
+        pre_spec: file
+            .pre_spec
+            .as_ref()
+            .map(|pre| lower_spec(pre, &types))
+            .transpose()?,
+        post_spec: lower_spec(&file.post_spec, &types)?,
+        types,
+    })
+}
+
+/// Lowers one spec; `types` must name every abstract type in it, as those from
+/// [`AbstractTypes::resolve`] do.
+pub fn lower_spec(spec: &Spec, types: &AbstractTypes) -> Result<ElrondSpec, LoweringError> {
+    let Spec(args, assertion) = spec;
+    let args = args
+        .iter()
+        .map(|Tpedvar(ty, name)| Ok(ElrondTpedvar(types.lower(ty)?, name.clone())))
+        .collect::<Result<Vec<_>, _>>()?;
+    let env: HashMap<&str, ElrondType> = args
+        .iter()
+        .map(|ElrondTpedvar(ty, name)| (name.as_str(), ty.clone()))
+        .collect();
+    let body = lower_assertion(assertion, &env)?;
+    Ok(ElrondSpec(args, ElrondForallFormula(vec![], body)))
+}
+
+fn lower_var(
+    name: &str,
+    env: &HashMap<&str, ElrondType>,
+) -> Result<ElrondSimpleExpr, LoweringError> {
+    env.get(name)
+        .map(|ty| ElrondSimpleExpr::Var(ty.clone(), name.to_string()))
+        .ok_or_else(|| LoweringError::UnboundVariable(name.to_string()))
+}
+
+fn lower_assertion(
+    assertion: &Assertion,
+    env: &HashMap<&str, ElrondType>,
+) -> Result<ElrondPred, LoweringError> {
+    let boxed = |a: &Assertion| lower_assertion(a, env).map(Box::new);
+    let all = |a_vec: &[Assertion]| {
+        a_vec
+            .iter()
+            .map(|a| lower_assertion(a, env))
+            .collect::<Result<Vec<_>, _>>()
+    };
+    Ok(match assertion {
+        Assertion::True => ElrondPred::True,
+        Assertion::Predicate(p, args) => ElrondPred::Atom(ElrondSimpleExpr::Op(
+            ElrondType::Bool,
+            p.surface_name().to_string(),
+            args.iter()
+                .map(|a| lower_var(a, env))
+                .collect::<Result<_, _>>()?,
+        )),
+        Assertion::Op(o, v1, v2) => ElrondPred::Atom(ElrondSimpleExpr::Op(
+            ElrondType::Bool,
+            o.elrond_name().to_string(),
+            vec![lower_var(v1, env)?, lower_var(v2, env)?],
+        )),
+        Assertion::Implies(a1, a2) => ElrondPred::Implies(boxed(a1)?, boxed(a2)?),
+        Assertion::Not(a) => ElrondPred::Not(boxed(a)?),
+        Assertion::And(a_vec) => ElrondPred::And(all(a_vec)?),
+        Assertion::Or(a_vec) => ElrondPred::Or(all(a_vec)?),
+        Assertion::Iff(a1, a2) => ElrondPred::Iff(boxed(a1)?, boxed(a2)?),
+    })
+}
+
+/* Lifting: ElrondAssertion -> AssertionFile */
+
+impl TryFrom<&ElrondAssertion> for AssertionFile {
+    type Error = LoweringError;
+
+    /// Lifts with datatypes written under the assertion's abstract type names, and
+    /// any others as `list` and `tree`.
+    fn try_from(assertion: &ElrondAssertion) -> Result<Self, Self::Error> {
+        lift_assertion(assertion, &assertion.types)
+    }
+}
+
+pub fn lift_assertion(
+    assertion: &ElrondAssertion,
+    types: &AbstractTypes,
+) -> Result<AssertionFile, LoweringError> {
+    Ok(AssertionFile {
+        preds: AssertionPredicates(assertion.preds.0.clone()),
+        pre_spec: assertion
+            .pre_spec
+            .as_ref()
+            .map(|pre| lift_spec(pre, types))
+            .transpose()?,
+        post_spec: lift_spec(&assertion.post_spec, types)?,
+    })
+}
+
+/// Assertion files have no quantifiers; the engine treats every spec argument that is
+/// not an input or output as universally quantified, so `FreeVar`s become arguments.
+pub fn lift_spec(spec: &ElrondSpec, types: &AbstractTypes) -> Result<Spec, LoweringError> {
+    let ElrondSpec(args, ElrondForallFormula(free_vars, pred)) = spec;
+    let args = args
+        .iter()
+        .map(|ElrondTpedvar(ty, name)| (ty, name))
+        .chain(free_vars.iter().map(|fv| (&fv.0, &fv.1)))
+        .map(|(ty, name)| Ok(Tpedvar(types.lift(ty)?, name.clone())))
+        .collect::<Result<Vec<_>, _>>()?;
+    let bound: Vec<&str> = args.iter().map(|Tpedvar(_, name)| name.as_str()).collect();
+    Ok(Spec(args.clone(), lift_pred(pred, &bound)?))
+}
+
+fn lift_var(expr: &ElrondSimpleExpr, bound: &[&str]) -> Result<String, LoweringError> {
+    match expr {
+        ElrondSimpleExpr::Var(_, name) if bound.contains(&name.as_str()) => Ok(name.clone()),
+        ElrondSimpleExpr::Var(_, name) => Err(LoweringError::UnboundVariable(name.clone())),
+        e => Err(LoweringError::UnsupportedExpr(e.to_string())),
+    }
+}
+
+fn lift_pred(pred: &ElrondPred, bound: &[&str]) -> Result<Assertion, LoweringError> {
+    let boxed = |p: &ElrondPred| lift_pred(p, bound).map(Box::new);
+    let all = |p_vec: &[ElrondPred]| {
+        p_vec
+            .iter()
+            .map(|p| lift_pred(p, bound))
+            .collect::<Result<Vec<_>, _>>()
+    };
+    Ok(match pred {
+        ElrondPred::True => Assertion::True,
+        ElrondPred::Atom(ElrondSimpleExpr::Op(_, op, args)) => {
+            if let Some(o) = AssertionOp::from_elrond_name(op) {
+                match args.as_slice() {
+                    [v1, v2] => Assertion::Op(o, lift_var(v1, bound)?, lift_var(v2, bound)?),
+                    _ => return Err(LoweringError::UnsupportedExpr(pred.to_string())),
+                }
+            } else if let Ok(p) = op.parse::<ElrondKnownPredicate>() {
+                Assertion::Predicate(
+                    p,
+                    args.iter()
+                        .map(|a| lift_var(a, bound))
+                        .collect::<Result<_, _>>()?,
+                )
+            } else {
+                return Err(LoweringError::UnsupportedExpr(pred.to_string()));
+            }
+        }
+        ElrondPred::Atom(e) => return Err(LoweringError::UnsupportedExpr(e.to_string())),
+        ElrondPred::Implies(p1, p2) => Assertion::Implies(boxed(p1)?, boxed(p2)?),
+        // Assertion files have no conditional, so spell it out
+        ElrondPred::Ite(c, t, e) => Assertion::And(vec![
+            Assertion::Implies(boxed(c)?, boxed(t)?),
+            Assertion::Implies(Box::new(Assertion::Not(boxed(c)?)), boxed(e)?),
+        ]),
+        ElrondPred::Not(p) => Assertion::Not(boxed(p)?),
+        ElrondPred::And(p_vec) => Assertion::And(all(p_vec)?),
+        ElrondPred::Or(p_vec) => Assertion::Or(all(p_vec)?),
+        ElrondPred::Iff(p1, p2) => Assertion::Iff(boxed(p1)?, boxed(p2)?),
+    })
+}
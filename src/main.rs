@@ -1,29 +1,41 @@
 use arwen_elrond_ipc::{
-    ipc::{ArwenMessage, ArwenSetup, ElrondKnownPredicate, ElrondPredicates},
+    ipc::{
+        ArwenInput, ArwenMessage, ArwenSetup, ElrondHoles, ElrondInferMode, ElrondKnownPredicate,
+        ElrondMessage, ElrondPredicates, EnginePredicates, InferenceOptions,
+    },
+    predicates::ElrondDatatype,
     Elrond,
 };
 
 #[cfg(test)]
-use arwen_elrond_ipc::ipc::{
-    ElrondAssertion, ElrondForallFormula, ElrondPred, ElrondSimpleExpr, ElrondSpec, ElrondTpedvar,
-    ElrondType,
+use arwen_elrond_ipc::{
+    ipc::{
+        ElrondAssertion, ElrondForallFormula, ElrondPred, ElrondSimpleExpr, ElrondSpec,
+        ElrondTpedvar, ElrondType,
+    },
+    lowering::AbstractTypes,
 };
 
 fn main() {
-    let mut e = Elrond::new();
-
-    e.send_message(ArwenMessage::Start).unwrap();
-
     let dir = "ADT-Lemma-Discovery".to_string();
     // Source ocaml file
-    let sourcefile = dir.clone() + "/data/customstk.ml";
-    let assertionfile = dir + "/data/customstk_assertion1.ml";
+    let sourcefile = ArwenInput::file(dir.clone() + "/data/customstk.ml");
+    let assertionfile = ArwenInput::file(dir + "/data/customstk_assertion1.ml");
     let outputdir = "customstk_out".to_string();
 
     // AKA the name of the function being verified
     let client_name = "concat".to_string();
 
-    // fnames are the component names
+    // fnames are the component names, the library functions whose specs are inferred
+    let holes = ElrondHoles {
+        holes: vec![
+            "Customstk.push".to_string(),
+            "Customstk.is_empty".to_string(),
+            "Customstk.top".to_string(),
+            "Customstk.tail".to_string(),
+        ],
+        trusted: vec![],
+    };
 
     // This is a little tricky, you will get weird results if some of these are missing
     let predicates = ElrondPredicates(vec![
@@ -31,18 +43,49 @@ fn main() {
         ElrondKnownPredicate::head,
     ]);
 
-    // todo build in a check such that only the list of provided predicates are used in any specifications
-
-    e.send_message(ArwenMessage::Setup(ArwenSetup {
+    let setup = ArwenSetup {
         sourcefile,
         assertionfile,
         outputdir,
         client_name,
-        predicates,
-    }))
-    .unwrap();
+        // Customstk.t is a stack, which the engine models as a list
+        predicates: EnginePredicates {
+            datatype: ElrondDatatype::List,
+            predicates: predicates.clone(),
+        },
+        mode: ElrondInferMode::Consistent,
+        holes: Some(holes),
+        options: InferenceOptions::default(),
+    };
+
+    match setup.validate() {
+        Ok(warnings) => {
+            for warning in warnings {
+                eprintln!("Warning: {warning}");
+            }
+        }
+        Err(errors) => {
+            for error in errors {
+                eprintln!("Invalid setup: {error}");
+            }
+            return;
+        }
+    }
+
+    let mut e = Elrond::new();
+
+    e.send_message(ArwenMessage::Start).unwrap();
+
+    e.send_message(ArwenMessage::Setup(setup)).unwrap();
 
-    println!("{}", e.receive_message());
+    let message = e.receive_message();
+    println!("{message}");
+    // Inferred specs should only use the predicates they were given
+    if let ElrondMessage::Result(_, result) = &message {
+        for issue in result.check_predicates(&predicates) {
+            eprintln!("Warning: {issue}");
+        }
+    }
 
     e.kill().unwrap();
 }
@@ -61,6 +104,7 @@ fn test_customstk_assertion1() {
             ElrondKnownPredicate::head,
         ]),
         pre_spec: None,
+        types: AbstractTypes::default(),
         post_spec: ElrondSpec(
             vec![
                 ElrondTpedvar(generic_type.clone(), "l1".to_string()),
@@ -131,5 +175,5 @@ fn test_customstk_assertion1() {
             ),
         ),
     };
-    insta::assert_display_snapshot!(assertion, my_assertion);
+    insta::assert_snapshot!(assertion, my_assertion);
 }
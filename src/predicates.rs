@@ -0,0 +1,144 @@
+use std::fmt::Display;
+
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::ipc::{ElrondKnownPredicate, ElrondType};
+
+/// The algebraic datatypes Elrond's predicates range over.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum ElrondDatatype {
+    List,
+    Tree,
+}
+
+impl ElrondDatatype {
+    pub fn elrond_type(&self) -> ElrondType {
+        match self {
+            ElrondDatatype::List => ElrondType::IntList,
+            ElrondDatatype::Tree => ElrondType::IntTree,
+        }
+    }
+
+    pub fn from_type(ty: &ElrondType) -> Option<ElrondDatatype> {
+        match ty {
+            ElrondType::IntList => Some(ElrondDatatype::List),
+            ElrondType::IntTree | ElrondType::IntTreeI | ElrondType::IntTreeB => {
+                Some(ElrondDatatype::Tree)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Display for ElrondDatatype {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ElrondDatatype::List => write!(f, "list"),
+            ElrondDatatype::Tree => write!(f, "tree"),
+        }
+    }
+}
+
+/// The type of a known predicate. Every predicate takes a datatype value first,
+/// followed by `args`, and returns `bool`.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct PredicateSignature {
+    pub predicate: ElrondKnownPredicate,
+    /// The datatypes the first argument may be.
+    pub datatypes: &'static [ElrondDatatype],
+    /// The types of the arguments after the datatype.
+    pub args: &'static [ElrondType],
+    pub description: &'static str,
+}
+
+const LIST: &[ElrondDatatype] = &[ElrondDatatype::List];
+const TREE: &[ElrondDatatype] = &[ElrondDatatype::Tree];
+const LIST_OR_TREE: &[ElrondDatatype] = &[ElrondDatatype::List, ElrondDatatype::Tree];
+
+const NO_INTS: &[ElrondType] = &[];
+const ONE_INT: &[ElrondType] = &[ElrondType::Int];
+const TWO_INTS: &[ElrondType] = &[ElrondType::Int, ElrondType::Int];
+
+impl ElrondKnownPredicate {
+    pub fn signature(&self) -> PredicateSignature {
+        let (datatypes, args, description) = match self {
+            ElrondKnownPredicate::length => (LIST, ONE_INT, "len l n: l has length n"),
+            ElrondKnownPredicate::sorted => (LIST, NO_INTS, "sorted l: l is sorted ascending"),
+            ElrondKnownPredicate::member => (LIST_OR_TREE, ONE_INT, "mem d u: u occurs in d"),
+            ElrondKnownPredicate::head => (LIST, ONE_INT, "hd l u: u is the first element of l"),
+            ElrondKnownPredicate::order => (
+                LIST,
+                TWO_INTS,
+                "ord l u v: an occurrence of u comes before an occurrence of v in l",
+            ),
+            ElrondKnownPredicate::once => (
+                LIST_OR_TREE,
+                ONE_INT,
+                "once d u: u occurs exactly once in d",
+            ),
+            ElrondKnownPredicate::left => (
+                TREE,
+                TWO_INTS,
+                "left t u v: v occurs in the left subtree of a node labelled u",
+            ),
+            ElrondKnownPredicate::right => (
+                TREE,
+                TWO_INTS,
+                "right t u v: v occurs in the right subtree of a node labelled u",
+            ),
+            ElrondKnownPredicate::para => (
+                TREE,
+                TWO_INTS,
+                "para t u v: u and v occur in the left and right subtrees of a common node",
+            ),
+            ElrondKnownPredicate::ance => (
+                TREE,
+                TWO_INTS,
+                "ance t u v: a node labelled u is an ancestor of a node labelled v",
+            ),
+            ElrondKnownPredicate::root => (TREE, ONE_INT, "root t u: the root of t is labelled u"),
+        };
+        PredicateSignature {
+            predicate: *self,
+            datatypes,
+            args,
+            description,
+        }
+    }
+}
+
+impl PredicateSignature {
+    /// The number of arguments, including the datatype.
+    pub fn arity(&self) -> usize {
+        1 + self.args.len()
+    }
+
+    pub fn applies_to(&self, datatype: ElrondDatatype) -> bool {
+        self.datatypes.contains(&datatype)
+    }
+
+    /// The full argument types when applied to `datatype`, or `None` if the
+    /// predicate does not apply to it.
+    pub fn arg_types(&self, datatype: ElrondDatatype) -> Option<Vec<ElrondType>> {
+        self.applies_to(datatype).then(|| {
+            std::iter::once(datatype.elrond_type())
+                .chain(self.args.iter().cloned())
+                .collect()
+        })
+    }
+}
+
+impl Display for PredicateSignature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let datatype = match self.datatypes {
+            [dt] => dt.to_string(),
+            dts => format!("({})", dts.iter().join(" | ")),
+        };
+        write!(f, "{} : {datatype}", self.predicate)?;
+        for arg in self.args {
+            write!(f, " -> {arg}")?;
+        }
+        write!(f, " -> bool")
+    }
+}
@@ -0,0 +1,549 @@
+//! A width-aware pretty printer for specs and assertions.
+//!
+//! The printer breaks long formulas across lines and only parenthesizes where
+//! precedence requires it; the `Display` impls of predicates and assertions are
+//! the same printer with [`PrettyConfig::flat`], on one line. `!` binds tighter
+//! than `&&`, which binds tighter than `||`. Nested conjunctions and disjunctions
+//! keep their parentheses, so the printed form determines the tree: in OCaml
+//! style, `AssertionFileParser` parses the output back to the same
+//! `AssertionFile`, as long as every `And` and `Or` has at least two operands and
+//! names are identifiers other than keywords.
+
+use itertools::Itertools;
+
+use crate::{
+    ipc::{
+        ElrondAssertion, ElrondForallFormula, ElrondPred, ElrondSimpleExpr, ElrondSpec,
+        ElrondTpedvar, FreeVar,
+    },
+    ipc_assertion::{Assertion, AssertionFile, AssertionOp, Spec, Tpedvar},
+    lowering::AbstractTypes,
+};
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum PrettyStyle {
+    /// The syntax of ADT-Lemma-Discovery assertion files: `implies (a) (b)`, `!a`.
+    #[default]
+    OCaml,
+    /// Mathematical notation: `a ⇒ b`, `¬a`, `args ⊢ body`.
+    Math,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct PrettyConfig {
+    /// The line width to fit output into. Defaults to `80`.
+    pub width: usize,
+    /// Spaces per indentation level. Defaults to `2`.
+    pub indent: usize,
+    pub style: PrettyStyle,
+}
+
+impl Default for PrettyConfig {
+    fn default() -> Self {
+        PrettyConfig {
+            width: 80,
+            indent: 2,
+            style: PrettyStyle::default(),
+        }
+    }
+}
+
+impl PrettyConfig {
+    /// A config that never breaks lines.
+    pub fn flat(style: PrettyStyle) -> Self {
+        PrettyConfig {
+            width: usize::MAX,
+            indent: 0,
+            style,
+        }
+    }
+}
+
+/// A document in the style of Wadler's "A prettier printer": each `Group` is
+/// laid out on one line if it fits, otherwise its `Line`s become newlines.
+#[derive(Debug, Clone)]
+enum Doc {
+    Text(String),
+    /// A space, or a newline at the current indentation.
+    Line,
+    /// Always a newline.
+    HardLine,
+    Concat(Vec<Doc>),
+    Nest(usize, Box<Doc>),
+    Group(Box<Doc>),
+}
+
+fn text(s: impl Into<String>) -> Doc {
+    Doc::Text(s.into())
+}
+
+fn nest(indent: usize, doc: Doc) -> Doc {
+    Doc::Nest(indent, Box::new(doc))
+}
+
+fn group(doc: Doc) -> Doc {
+    Doc::Group(Box::new(doc))
+}
+
+fn parens(doc: Doc) -> Doc {
+    Doc::Concat(vec![text("("), nest(1, doc), text(")")])
+}
+
+impl Doc {
+    fn render(&self, width: usize) -> String {
+        let width = width.min(isize::MAX as usize) as isize;
+        let mut out = String::new();
+        let mut column = 0;
+        let mut stack = vec![(0, false, self)];
+        while let Some((indent, flat, doc)) = stack.pop() {
+            match doc {
+                Doc::Text(s) => {
+                    out.push_str(s);
+                    column += s.chars().count() as isize;
+                }
+                Doc::Line if flat => {
+                    out.push(' ');
+                    column += 1;
+                }
+                Doc::Line | Doc::HardLine => {
+                    // Trailing spaces come from an indented empty line
+                    while out.ends_with(' ') {
+                        out.pop();
+                    }
+                    out.push('\n');
+                    out.extend(std::iter::repeat_n(' ', indent));
+                    column = indent as isize;
+                }
+                Doc::Concat(docs) => stack.extend(docs.iter().rev().map(|d| (indent, flat, d))),
+                Doc::Nest(i, doc) => stack.push((indent + i, flat, doc)),
+                Doc::Group(doc) => {
+                    let flat = flat || fits(width - column, doc, &stack);
+                    stack.push((indent, flat, doc));
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Whether `group` laid out flat, and whatever follows it up to the next line
+/// break, fits in `width` columns.
+fn fits(mut width: isize, group: &Doc, rest: &[(usize, bool, &Doc)]) -> bool {
+    let mut todo = vec![(true, group)];
+    let mut rest = rest.iter().rev();
+    while width >= 0 {
+        let Some((flat, doc)) = todo
+            .pop()
+            .or_else(|| rest.next().map(|(_, flat, doc)| (*flat, *doc)))
+        else {
+            return true;
+        };
+        match doc {
+            Doc::Text(s) => width -= s.chars().count() as isize,
+            Doc::Line if flat => width -= 1,
+            Doc::Line | Doc::HardLine => return true,
+            Doc::Concat(docs) => todo.extend(docs.iter().rev().map(|d| (flat, d))),
+            Doc::Nest(_, doc) | Doc::Group(doc) => todo.push((flat, doc)),
+        }
+    }
+    false
+}
+
+/// Binding strength, loosest first. An operand is parenthesized when it binds
+/// more loosely than its position requires.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+enum Prec {
+    Ite,
+    Iff,
+    Implies,
+    Or,
+    And,
+    Cmp,
+    Not,
+    App,
+    Atom,
+}
+
+/// A document together with how tightly it binds.
+struct Printed(Doc, Prec);
+
+impl Printed {
+    fn at(self, min: Prec) -> Doc {
+        let Printed(doc, prec) = self;
+        if prec < min {
+            parens(doc)
+        } else {
+            doc
+        }
+    }
+}
+
+struct Printer<'a> {
+    config: &'a PrettyConfig,
+}
+
+impl Printer<'_> {
+    fn math(&self) -> bool {
+        self.config.style == PrettyStyle::Math
+    }
+
+    fn indented(&self, doc: Doc) -> Doc {
+        nest(self.config.indent, doc)
+    }
+
+    fn cmp(&self, op: &AssertionOp) -> String {
+        match (op, self.math()) {
+            (AssertionOp::Eq, true) => "=".to_string(),
+            (AssertionOp::Le, true) => "≤".to_string(),
+            (AssertionOp::Ge, true) => "≥".to_string(),
+            (AssertionOp::Ne, true) => "≠".to_string(),
+            (op, _) => op.to_string(),
+        }
+    }
+
+    fn not(&self, p: Printed) -> Printed {
+        let sign = if self.math() { "¬" } else { "!" };
+        Printed(Doc::Concat(vec![text(sign), p.at(Prec::Not)]), Prec::Not)
+    }
+
+    /// Empty and singleton junctions have no syntax of their own, so they print as
+    /// their value and their operand respectively.
+    fn junction(&self, mut ps: Vec<Printed>, and: bool) -> Printed {
+        match ps.len() {
+            0 if and => return self.truth(),
+            0 => return self.not(self.truth()),
+            1 => return ps.pop().unwrap(),
+            _ => {}
+        }
+        let (op, prec) = match (and, self.math()) {
+            (true, false) => (" &&", Prec::And),
+            (true, true) => (" ∧", Prec::And),
+            (false, false) => (" ||", Prec::Or),
+            (false, true) => (" ∨", Prec::Or),
+        };
+        // Operands of the same connective keep their parentheses
+        let min = if and { Prec::Cmp } else { Prec::And };
+        let operands = ps.into_iter().map(|p| p.at(min)).collect_vec();
+        let mut docs = vec![];
+        for (i, doc) in operands.into_iter().enumerate() {
+            if i > 0 {
+                docs.extend([text(op), Doc::Line]);
+            }
+            docs.push(doc);
+        }
+        Printed(group(Doc::Concat(docs)), prec)
+    }
+
+    fn binary(&self, lhs: Printed, rhs: Printed, iff: bool) -> Printed {
+        if self.math() {
+            let (op, prec, lhs_min, rhs_min) = if iff {
+                (" ⇔", Prec::Iff, Prec::Implies, Prec::Implies)
+            } else {
+                (" ⇒", Prec::Implies, Prec::Or, Prec::Implies)
+            };
+            let doc = Doc::Concat(vec![
+                lhs.at(lhs_min),
+                text(op),
+                self.indented(Doc::Concat(vec![Doc::Line, rhs.at(rhs_min)])),
+            ]);
+            Printed(group(doc), prec)
+        } else {
+            let keyword = if iff { "iff" } else { "implies" };
+            let doc = Doc::Concat(vec![
+                text(keyword),
+                self.indented(Doc::Concat(vec![
+                    Doc::Line,
+                    parens(lhs.0),
+                    Doc::Line,
+                    parens(rhs.0),
+                ])),
+            ]);
+            Printed(group(doc), Prec::App)
+        }
+    }
+
+    fn ite(&self, c: Printed, t: Printed, e: Printed) -> Printed {
+        let doc = Doc::Concat(vec![
+            text("if "),
+            nest(3, c.0),
+            Doc::Line,
+            text("then "),
+            self.indented(t.0),
+            Doc::Line,
+            text("else "),
+            self.indented(e.0),
+        ]);
+        Printed(group(doc), Prec::Ite)
+    }
+
+    fn truth(&self) -> Printed {
+        Printed(text(if self.math() { "⊤" } else { "true" }), Prec::Atom)
+    }
+
+    fn app(&self, f: String, args: Vec<Printed>) -> Printed {
+        if args.is_empty() {
+            return Printed(text(f), Prec::Atom);
+        }
+        let mut docs = vec![text(f)];
+        for arg in args {
+            docs.extend([Doc::Line, arg.at(Prec::Atom)]);
+        }
+        Printed(group(self.indented(Doc::Concat(docs))), Prec::App)
+    }
+
+    fn comparison(&self, op: &AssertionOp, lhs: Printed, rhs: Printed) -> Printed {
+        let doc = Doc::Concat(vec![
+            lhs.at(Prec::App),
+            text(format!(" {} ", self.cmp(op))),
+            rhs.at(Prec::App),
+        ]);
+        Printed(doc, Prec::Cmp)
+    }
+
+    fn expr(&self, e: &ElrondSimpleExpr) -> Printed {
+        match e {
+            ElrondSimpleExpr::Literal(_, lit) => Printed(text(lit.to_string()), Prec::Atom),
+            ElrondSimpleExpr::Var(_, name) => Printed(text(name), Prec::Atom),
+            ElrondSimpleExpr::Op(_, op, args) => match AssertionOp::from_elrond_name(op) {
+                Some(op) if args.len() == 2 => {
+                    self.comparison(&op, self.expr(&args[0]), self.expr(&args[1]))
+                }
+                _ => self.app(op.clone(), args.iter().map(|a| self.expr(a)).collect()),
+            },
+            ElrondSimpleExpr::Tuple(t) => {
+                let mut docs = vec![];
+                for (i, e) in t.iter().enumerate() {
+                    if i > 0 {
+                        docs.extend([text(","), Doc::Line]);
+                    }
+                    docs.push(self.expr(e).0);
+                }
+                Printed(group(parens(Doc::Concat(docs))), Prec::Atom)
+            }
+        }
+    }
+
+    fn pred(&self, p: &ElrondPred) -> Printed {
+        match p {
+            ElrondPred::True => self.truth(),
+            ElrondPred::Atom(e) => self.expr(e),
+            ElrondPred::Implies(p1, p2) => self.binary(self.pred(p1), self.pred(p2), false),
+            ElrondPred::Iff(p1, p2) => self.binary(self.pred(p1), self.pred(p2), true),
+            ElrondPred::Ite(c, p1, p2) => self.ite(self.pred(c), self.pred(p1), self.pred(p2)),
+            ElrondPred::Not(p) => self.not(self.pred(p)),
+            ElrondPred::And(p_vec) => {
+                self.junction(p_vec.iter().map(|p| self.pred(p)).collect(), true)
+            }
+            ElrondPred::Or(p_vec) => {
+                self.junction(p_vec.iter().map(|p| self.pred(p)).collect(), false)
+            }
+        }
+    }
+
+    fn assertion(&self, a: &Assertion) -> Printed {
+        let name = |s: &String| Printed(text(s), Prec::Atom);
+        match a {
+            Assertion::True => self.truth(),
+            Assertion::Predicate(p, args) => {
+                self.app(p.to_string(), args.iter().map(name).collect())
+            }
+            Assertion::Op(op, v1, v2) => self.comparison(op, name(v1), name(v2)),
+            Assertion::Implies(a1, a2) => {
+                self.binary(self.assertion(a1), self.assertion(a2), false)
+            }
+            Assertion::Iff(a1, a2) => self.binary(self.assertion(a1), self.assertion(a2), true),
+            Assertion::Not(a) => self.not(self.assertion(a)),
+            Assertion::And(a_vec) => {
+                self.junction(a_vec.iter().map(|a| self.assertion(a)).collect(), true)
+            }
+            Assertion::Or(a_vec) => {
+                self.junction(a_vec.iter().map(|a| self.assertion(a)).collect(), false)
+            }
+        }
+    }
+
+    /// `binder args sep body`, breaking before the body when it does not fit.
+    fn binding(&self, binder: &str, args: Vec<String>, sep: &str, body: Doc) -> Doc {
+        let mut head = vec![text(binder)];
+        for arg in args {
+            head.extend([Doc::Line, text(arg)]);
+        }
+        group(Doc::Concat(vec![
+            group(self.indented(Doc::Concat(head))),
+            text(sep),
+            self.indented(Doc::Concat(vec![Doc::Line, body])),
+        ]))
+    }
+
+    fn forall(&self, ElrondForallFormula(vars, body): &ElrondForallFormula) -> Doc {
+        let body = self.pred(body).0;
+        if vars.is_empty() {
+            return body;
+        }
+        if self.math() {
+            let vars = vars.iter().map(ToString::to_string).join(", ");
+            self.binding("∀", vec![vars], ".", body)
+        } else {
+            let vars = vars.iter().map(|v| format!("({v})")).collect();
+            self.binding("fun", vars, " ->", body)
+        }
+    }
+
+    fn spec(&self, ElrondSpec(args, formula): &ElrondSpec) -> Doc {
+        if self.math() {
+            let args = args.iter().map(ToString::to_string).collect();
+            self.turnstile(args, self.forall(formula))
+        } else {
+            // Assertion files have no quantifiers, so free variables are written as arguments
+            let ElrondForallFormula(free_vars, body) = formula;
+            let args = args
+                .iter()
+                .map(|ElrondTpedvar(ty, name)| format!("({name} : {ty})"))
+                .chain(
+                    free_vars
+                        .iter()
+                        .map(|FreeVar(ty, name)| format!("({name} : {ty})")),
+                )
+                .collect();
+            self.binding("fun", args, " ->", self.pred(body).0)
+        }
+    }
+
+    /// `args ⊢ body`, or just the body if there are no arguments.
+    fn turnstile(&self, args: Vec<String>, body: Doc) -> Doc {
+        if args.is_empty() {
+            return body;
+        }
+        self.binding(&args.join(", "), vec![], " ⊢", body)
+    }
+
+    /// A spec as a definition in an assertion file, or a labelled line in math style.
+    fn definition(&self, kind: &str, args: Vec<String>, body: Doc) -> Doc {
+        if self.math() {
+            Doc::Concat(vec![text(format!("{kind}: ")), self.turnstile(args, body)])
+        } else {
+            let mut head = vec![text(format!("let {kind}"))];
+            for arg in args {
+                head.extend([Doc::Line, text(format!("({arg})"))]);
+            }
+            Doc::Concat(vec![
+                group(self.indented(Doc::Concat(head))),
+                text(" ="),
+                self.indented(Doc::Concat(vec![Doc::HardLine, body])),
+            ])
+        }
+    }
+
+    fn file(&self, preds: String, pre: Option<Doc>, post: Doc) -> Doc {
+        let mut docs = vec![text(preds), Doc::HardLine];
+        if let Some(pre) = pre {
+            docs.extend([pre, Doc::HardLine]);
+        }
+        docs.extend([Doc::HardLine, post, Doc::HardLine]);
+        Doc::Concat(docs)
+    }
+
+    fn elrond_definition(
+        &self,
+        kind: &str,
+        ElrondSpec(args, formula): &ElrondSpec,
+        types: &AbstractTypes,
+    ) -> Doc {
+        let ElrondForallFormula(free_vars, body) = formula;
+        let args = args
+            .iter()
+            .map(|ElrondTpedvar(ty, name)| (ty, name))
+            .chain(free_vars.iter().map(|FreeVar(ty, name)| (ty, name)))
+            .map(|(ty, name)| {
+                if self.math() {
+                    format!("{name} : {ty}")
+                } else {
+                    format!("{name} : {}", types.type_name(ty))
+                }
+            })
+            .collect();
+        self.definition(kind, args, self.pred(body).0)
+    }
+
+    fn assertion_definition(&self, kind: &str, Spec(args, body): &Spec) -> Doc {
+        self.definition(kind, tpedvars(args), self.assertion(body).0)
+    }
+}
+
+/// Assertion arguments without their parentheses, as in `u : int`.
+fn tpedvars(args: &[Tpedvar]) -> Vec<String> {
+    args.iter()
+        .map(|Tpedvar(ty, name)| format!("{name} : {ty}"))
+        .collect()
+}
+
+impl PrettyConfig {
+    fn print(&self, doc: impl FnOnce(&Printer) -> Doc) -> String {
+        doc(&Printer { config: self }).render(self.width)
+    }
+}
+
+impl ElrondPred {
+    pub fn pretty(&self, config: &PrettyConfig) -> String {
+        config.print(|p| p.pred(self).0)
+    }
+}
+
+impl ElrondForallFormula {
+    pub fn pretty(&self, config: &PrettyConfig) -> String {
+        config.print(|p| p.forall(self))
+    }
+}
+
+impl ElrondSpec {
+    pub fn pretty(&self, config: &PrettyConfig) -> String {
+        config.print(|p| p.spec(self))
+    }
+}
+
+impl ElrondAssertion {
+    pub fn pretty(&self, config: &PrettyConfig) -> String {
+        config.print(|p| {
+            let pre = self
+                .pre_spec
+                .as_ref()
+                .map(|pre| p.elrond_definition("pre", pre, &self.types));
+            let post = p.elrond_definition("post", &self.post_spec, &self.types);
+            p.file(self.preds.to_string(), pre, post)
+        })
+    }
+}
+
+impl Assertion {
+    pub fn pretty(&self, config: &PrettyConfig) -> String {
+        config.print(|p| p.assertion(self).0)
+    }
+}
+
+impl Spec {
+    pub fn pretty(&self, config: &PrettyConfig) -> String {
+        config.print(|p| {
+            let Spec(args, body) = self;
+            let body = p.assertion(body).0;
+            if p.math() {
+                p.turnstile(tpedvars(args), body)
+            } else {
+                let args = args.iter().map(ToString::to_string).collect();
+                p.binding("fun", args, " ->", body)
+            }
+        })
+    }
+}
+
+impl AssertionFile {
+    pub fn pretty(&self, config: &PrettyConfig) -> String {
+        config.print(|p| {
+            let pre = self
+                .pre_spec
+                .as_ref()
+                .map(|pre| p.assertion_definition("pre", pre));
+            let post = p.assertion_definition("post", &self.post_spec);
+            p.file(self.preds.to_string(), pre, post)
+        })
+    }
+}
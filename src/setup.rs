@@ -0,0 +1,279 @@
+use std::{fmt::Display, path::Path};
+
+use itertools::Itertools;
+
+use crate::{
+    assertion_parser::AssertionFileParser,
+    ipc::{ArwenSetup, ElrondInferMode, ElrondKnownPredicate, InferenceOptions},
+    ipc_assertion::{Assertion, AssertionFile},
+    predicates::ElrondDatatype,
+    usage::PredicateIssue,
+};
+
+/// The library functions a benchmark source declares.
+/// See translate/translate.ml -> parse_source
+#[derive(PartialEq, Eq, Debug)]
+pub struct SourceSignature {
+    /// Qualified names such as `Customstk.push`.
+    pub functions: Vec<String>,
+}
+
+impl SourceSignature {
+    /// Collects the `val` declarations of every `module type Name = sig ... end` in the
+    /// source. Sources without a signature block fall back to the qualified
+    /// `Module.function` names the client calls.
+    pub fn from_source(source: &str) -> SourceSignature {
+        let declared = declared_functions(source);
+        let functions = if declared.is_empty() {
+            qualified_references(source)
+        } else {
+            declared
+        };
+        SourceSignature { functions }
+    }
+
+    pub fn contains(&self, function: &str) -> bool {
+        self.functions.iter().any(|f| f == function)
+    }
+}
+
+fn declared_functions(source: &str) -> Vec<String> {
+    let mut functions = vec![];
+    let mut module = None;
+    let mut tokens = source.split_whitespace().peekable();
+    while let Some(token) = tokens.next() {
+        match (token, &module) {
+            ("module", None) if tokens.peek() == Some(&"type") => {
+                tokens.next();
+                module = tokens.next().map(ToString::to_string);
+            }
+            ("val", Some(m)) => {
+                if let Some(name) = tokens.next() {
+                    functions.push(format!("{m}.{}", name.trim_end_matches(':')));
+                }
+            }
+            ("end", Some(_)) => module = None,
+            _ => {}
+        }
+    }
+    functions.into_iter().unique().collect()
+}
+
+fn qualified_references(source: &str) -> Vec<String> {
+    source
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.' || c == '\''))
+        .filter_map(|word| {
+            let (module, function) = word.rsplit_once('.')?;
+            let is_module = module.starts_with(|c: char| c.is_ascii_uppercase());
+            let is_function = function.starts_with(|c: char| c.is_ascii_lowercase() || c == '_');
+            (is_module && is_function && function != "t").then(|| word.to_string())
+        })
+        .unique()
+        .collect()
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum SetupError {
+    /// The source or assertion could not be read.
+    Input(String),
+    /// A hole or trusted function that the source's signature does not declare.
+    UnknownFunction(String),
+    /// A function listed as both a hole and trusted.
+    HoleAndTrusted(String),
+    /// Every candidate hole is trusted, so there is nothing to infer.
+    NoHoles,
+    /// An inference option the engine cannot run with.
+    InvalidOption(String),
+    /// The assertion file does not parse.
+    Assertion(String),
+    /// A predicate applied to the wrong number of arguments.
+    Arity {
+        predicate: ElrondKnownPredicate,
+        expected: usize,
+        found: usize,
+    },
+    /// A predicate the assertion applies without declaring it, or that is not a
+    /// known predicate.
+    Predicate(PredicateIssue),
+    /// A predicate the setup lets the engine use that does not apply to its datatype.
+    Datatype(ElrondKnownPredicate, ElrondDatatype),
+    /// Weakening was requested, but no consistent specs are saved at this path.
+    MissingConsistentSpecs(String),
+}
+
+impl Display for SetupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SetupError::Input(e) => write!(f, "could not read input: {e}"),
+            SetupError::UnknownFunction(name) => {
+                write!(f, "{name} is not declared by the source's signature")
+            }
+            SetupError::HoleAndTrusted(name) => {
+                write!(f, "{name} is listed as both a hole and trusted")
+            }
+            SetupError::NoHoles => write!(f, "every library function is trusted"),
+            SetupError::InvalidOption(e) => write!(f, "invalid inference option: {e}"),
+            SetupError::Assertion(e) => write!(f, "could not parse assertion: {e}"),
+            SetupError::Arity {
+                predicate,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{} is applied to {found} arguments, expected {expected}",
+                predicate.signature()
+            ),
+            SetupError::Predicate(issue) => write!(f, "{issue}"),
+            SetupError::Datatype(predicate, datatype) => {
+                write!(
+                    f,
+                    "{} does not apply to a {datatype}",
+                    predicate.signature()
+                )
+            }
+            SetupError::MissingConsistentSpecs(path) => {
+                write!(f, "weakening needs the consistent specs saved to {path}")
+            }
+        }
+    }
+}
+
+impl ArwenSetup {
+    /// Where the consistent phase saves its specs, and where weakening reads them.
+    /// The engine writes into `outputdir` with a leading `_`, so `customstk_out`
+    /// ends up in `_customstk_out`.
+    /// See stub/stub.ml -> consistent_file
+    pub fn consistent_specs(&self) -> String {
+        format!("_{}/_consistent.json", self.outputdir)
+    }
+
+    /// Checks the setup before it is sent, reporting every problem found.
+    ///
+    /// Declared predicates that the assertion never applies do not stop the
+    /// setup from running, so they are returned as warnings instead.
+    pub fn validate(&self) -> Result<Vec<PredicateIssue>, Vec<SetupError>> {
+        let source = self
+            .sourcefile
+            .read()
+            .map_err(|e| vec![SetupError::Input(e)])?;
+        let signature = SourceSignature::from_source(&source);
+
+        let mut errors = vec![];
+        let mut warnings = vec![];
+        if let Some(holes) = &self.holes {
+            for name in holes.holes.iter().chain(&holes.trusted).unique() {
+                if !signature.contains(name) {
+                    errors.push(SetupError::UnknownFunction(name.clone()));
+                }
+            }
+            for name in holes.holes.iter().filter(|h| holes.trusted.contains(h)) {
+                errors.push(SetupError::HoleAndTrusted(name.clone()));
+            }
+
+            let candidates = if holes.holes.is_empty() {
+                &signature.functions
+            } else {
+                &holes.holes
+            };
+            if candidates.iter().all(|h| holes.trusted.contains(h)) {
+                errors.push(SetupError::NoHoles);
+            }
+        }
+
+        for p in &self.predicates.predicates.0 {
+            if p.engine_name(self.predicates.datatype).is_none() {
+                errors.push(SetupError::Datatype(*p, self.predicates.datatype));
+            }
+        }
+
+        match self.assertionfile.read() {
+            Ok(assertion) => match AssertionFileParser::new().parse(&assertion) {
+                Ok(assertion) => {
+                    errors.extend(check_arities(&assertion));
+                    for issue in assertion.check_predicates(&self.predicates.predicates.0) {
+                        match issue {
+                            PredicateIssue::Unused(_) => warnings.push(issue),
+                            _ => errors.push(SetupError::Predicate(issue)),
+                        }
+                    }
+                }
+                Err(e) => errors.push(SetupError::Assertion(e.to_string())),
+            },
+            Err(e) => errors.push(SetupError::Input(e)),
+        }
+
+        errors.extend(self.options.validate());
+
+        let path = self.consistent_specs();
+        if self.mode == ElrondInferMode::Weakening && !Path::new(&path).exists() {
+            errors.push(SetupError::MissingConsistentSpecs(path));
+        }
+
+        if errors.is_empty() {
+            Ok(warnings)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+fn check_arities(file: &AssertionFile) -> Vec<SetupError> {
+    fn walk(assertion: &Assertion, errors: &mut Vec<SetupError>) {
+        match assertion {
+            Assertion::True | Assertion::Op(..) => {}
+            Assertion::Predicate(p, args) => {
+                let expected = p.signature().arity();
+                if args.len() != expected {
+                    errors.push(SetupError::Arity {
+                        predicate: *p,
+                        expected,
+                        found: args.len(),
+                    });
+                }
+            }
+            Assertion::Implies(a1, a2) | Assertion::Iff(a1, a2) => {
+                walk(a1, errors);
+                walk(a2, errors);
+            }
+            Assertion::Not(a) => walk(a, errors),
+            Assertion::And(a_vec) | Assertion::Or(a_vec) => {
+                a_vec.iter().for_each(|a| walk(a, errors))
+            }
+        }
+    }
+
+    let mut errors = vec![];
+    for spec in file.pre_spec.iter().chain([&file.post_spec]) {
+        walk(&spec.1, &mut errors);
+    }
+    errors
+}
+
+impl InferenceOptions {
+    fn validate(&self) -> Vec<SetupError> {
+        let mut errors = vec![];
+        if self.bound == 0 {
+            errors.push(SetupError::InvalidOption(
+                "bound must be at least 1".to_string(),
+            ));
+        }
+        // The stub has no engine setting to pass these on to yet, so a value
+        // would be silently ignored
+        if self.max_quantified_vars.is_some() {
+            errors.push(SetupError::InvalidOption(
+                "max_quantified_vars is not supported by the stub yet; leave it unset".to_string(),
+            ));
+        }
+        if self.sample_budget.is_some() {
+            errors.push(SetupError::InvalidOption(
+                "sample_budget is not supported by the stub yet; leave it unset".to_string(),
+            ));
+        }
+        if self.phase_timeout_secs == Some(0) {
+            errors.push(SetupError::InvalidOption(
+                "phase_timeout_secs must be at least 1".to_string(),
+            ));
+        }
+        errors
+    }
+}
@@ -0,0 +1,188 @@
+//! Shrinks counterexamples while preserving the violation of the post spec.
+
+use std::fmt::Display;
+
+use itertools::Itertools;
+
+use crate::{
+    eval::EvalError,
+    explain::{Bindings, Explanation},
+    ipc::{Counterexample, ElrondAssertion, ElrondResult, ElrondSpec, ElrondTree, ElrondValue},
+};
+
+/// A counterexample and a smaller one that violates the spec in the same way,
+/// failing along the same [`Explanation::trace`].
+#[derive(PartialEq, Debug, Clone)]
+pub struct Shrunk {
+    pub original: Counterexample,
+    pub shrunk: Counterexample,
+}
+
+impl Display for Shrunk {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Original:\n{}Shrunk:\n{}", self.original, self.shrunk)
+    }
+}
+
+impl ElrondSpec {
+    /// Shrinks `cex`, which binds every argument by name, while it still violates
+    /// this spec, or returns `None` if it does not violate it to begin with.
+    pub fn shrink(&self, cex: &Counterexample) -> Result<Option<Shrunk>, EvalError> {
+        self.shrink_with(cex, &Bindings::by_name(&self.0))
+    }
+
+    /// Shrinks `cex` while it still violates this spec with its arguments bound by
+    /// `bindings`, or returns `None` if it does not violate it to begin with.
+    pub fn shrink_with(
+        &self,
+        cex: &Counterexample,
+        bindings: &Bindings,
+    ) -> Result<Option<Shrunk>, EvalError> {
+        let Some(original) = self.explain_with(cex, bindings)? else {
+            return Ok(None);
+        };
+        let violates =
+            |cex: &Counterexample| same_violation(self.explain_with(cex, bindings), &original);
+        Ok(Some(shrink(cex, bindings, violates)))
+    }
+}
+
+impl ElrondAssertion {
+    /// Shrinks each counterexample in `result`, with the arguments bound by
+    /// `bindings`, keeping the post spec violated and the pre spec, if any,
+    /// satisfied.
+    pub fn shrink(
+        &self,
+        result: &ElrondResult,
+        bindings: &Bindings,
+    ) -> Result<Vec<Option<Shrunk>>, EvalError> {
+        let ElrondResult::Cex(cexs) = result else {
+            return Ok(vec![]);
+        };
+        cexs.iter()
+            .map(|cex| {
+                let Some(original) = self.explain_cex(cex, bindings)? else {
+                    return Ok(None);
+                };
+                let violates = |cex: &Counterexample| {
+                    same_violation(self.explain_cex(cex, bindings), &original)
+                };
+                Ok(Some(shrink(cex, bindings, violates)))
+            })
+            .collect()
+    }
+}
+
+/// Whether a shrunk counterexample still violates the spec along the same trace,
+/// so shrinking cannot trade the original failure for a different one.
+fn same_violation(
+    explanation: Result<Option<Explanation>, EvalError>,
+    original: &Explanation,
+) -> bool {
+    matches!(explanation, Ok(Some(e)) if e.trace == original.trace)
+}
+
+/// Greedily applies the first smaller candidate that still `violates`, until none do.
+fn shrink(
+    cex: &Counterexample,
+    bindings: &Bindings,
+    violates: impl Fn(&Counterexample) -> bool,
+) -> Shrunk {
+    // Variables no argument is bound to, such as the client's intermediate
+    // results, cannot matter
+    let vars = bindings.vars();
+    let mut current = cex.clone();
+    for name in cex.names() {
+        if !vars.iter().any(|v| v == name) {
+            current.remove(name);
+        }
+    }
+    if !violates(&current) {
+        current = cex.clone();
+    }
+
+    'search: loop {
+        for (name, val) in current.clone().iter() {
+            for candidate in candidates(val) {
+                let mut next = current.clone();
+                next.insert(name, candidate);
+                if violates(&next) {
+                    current = next;
+                    continue 'search;
+                }
+            }
+        }
+        break;
+    }
+
+    Shrunk {
+        original: cex.clone(),
+        shrunk: current,
+    }
+}
+
+/// Smaller values to try in place of `val`, most aggressive first.
+fn candidates(val: &ElrondValue) -> Vec<ElrondValue> {
+    match val {
+        ElrondValue::I(i) => int_candidates(*i).into_iter().map(ElrondValue::I).collect(),
+        ElrondValue::B(true) => vec![ElrondValue::B(false)],
+        ElrondValue::L(l) => list_candidates(l).into_iter().map(ElrondValue::L).collect(),
+        ElrondValue::T(t) => tree_candidates(t).into_iter().map(ElrondValue::T).collect(),
+        ElrondValue::B(false) | ElrondValue::NotADt => vec![],
+    }
+}
+
+fn int_candidates(i: i64) -> Vec<i64> {
+    [0, i / 2, i - i.signum()]
+        .into_iter()
+        .filter(|c| *c != i)
+        .unique()
+        .collect()
+}
+
+/// Removes ever smaller chunks, then shrinks single elements.
+fn list_candidates(l: &[i64]) -> Vec<Vec<i64>> {
+    let mut out = vec![];
+    let mut chunk = l.len();
+    while chunk > 0 {
+        for start in (0..l.len()).step_by(chunk) {
+            let end = (start + chunk).min(l.len());
+            out.push([&l[..start], &l[end..]].concat());
+        }
+        chunk /= 2;
+    }
+    for (i, x) in l.iter().enumerate() {
+        for c in int_candidates(*x) {
+            let mut smaller = l.to_vec();
+            smaller[i] = c;
+            out.push(smaller);
+        }
+    }
+    out
+}
+
+/// Replaces the tree by a leaf or a subtree, then shrinks the root label and the
+/// subtrees in place.
+fn tree_candidates(t: &ElrondTree) -> Vec<ElrondTree> {
+    let ElrondTree::Node(x, left, right) = t else {
+        return vec![];
+    };
+    let mut out = vec![ElrondTree::Leaf, *left.clone(), *right.clone()];
+    out.extend(
+        int_candidates(*x)
+            .into_iter()
+            .map(|c| ElrondTree::Node(c, left.clone(), right.clone())),
+    );
+    out.extend(
+        tree_candidates(left)
+            .into_iter()
+            .map(|l| ElrondTree::Node(*x, Box::new(l), right.clone())),
+    );
+    out.extend(
+        tree_candidates(right)
+            .into_iter()
+            .map(|r| ElrondTree::Node(*x, left.clone(), Box::new(r))),
+    );
+    out.retain(|c| c != t);
+    out
+}
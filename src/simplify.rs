@@ -0,0 +1,285 @@
+//! Simplification and normal forms for `ElrondPred`.
+//!
+//! There is no `False` constructor, so false is written `Not(True)`.
+
+use std::fmt::Display;
+
+use crate::{
+    ipc::{ElrondForallFormula, ElrondPred, ElrondResult, ElrondSpec},
+    visit::{walk_pred_fold, Fold},
+};
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum NormalForm {
+    /// Only simplify; keep the connectives as they are.
+    #[default]
+    Simplified,
+    /// Replace `Ite` by implications.
+    IteFree,
+    /// Negation normal form: only `And`, `Or` and negated atoms.
+    Nnf,
+    /// A conjunction of disjunctions of literals.
+    Cnf,
+    /// A disjunction of conjunctions of literals.
+    Dnf,
+}
+
+impl Display for NormalForm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NormalForm::Simplified => write!(f, "simplified"),
+            NormalForm::IteFree => write!(f, "ite-free"),
+            NormalForm::Nnf => write!(f, "NNF"),
+            NormalForm::Cnf => write!(f, "CNF"),
+            NormalForm::Dnf => write!(f, "DNF"),
+        }
+    }
+}
+
+/// The result of simplifying a predicate, with the sizes before and after as
+/// measured by [`ElrondPred::size`].
+#[derive(PartialEq, Debug, Clone)]
+pub struct Simplification {
+    pub pred: ElrondPred,
+    pub size_before: u32,
+    pub size_after: u32,
+}
+
+impl Display for Simplification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} (size {} -> {})",
+            self.pred, self.size_before, self.size_after
+        )
+    }
+}
+
+fn falsity() -> ElrondPred {
+    ElrondPred::Not(Box::new(ElrondPred::True))
+}
+
+fn is_false(p: &ElrondPred) -> bool {
+    matches!(p, ElrondPred::Not(p) if **p == ElrondPred::True)
+}
+
+fn not(p: ElrondPred) -> ElrondPred {
+    match p {
+        ElrondPred::Not(p) => *p,
+        p => ElrondPred::Not(Box::new(p)),
+    }
+}
+
+impl ElrondPred {
+    /// Flattens nested `And`/`Or`, folds `True` and `Not(True)`, removes double
+    /// negations and duplicate operands.
+    pub fn simplify(&self) -> ElrondPred {
+        match self {
+            ElrondPred::True | ElrondPred::Atom(_) => self.clone(),
+            ElrondPred::Not(p) => not(p.simplify()),
+            ElrondPred::And(p_vec) => junction(p_vec, true),
+            ElrondPred::Or(p_vec) => junction(p_vec, false),
+            ElrondPred::Implies(p1, p2) => {
+                let (p1, p2) = (p1.simplify(), p2.simplify());
+                if p1 == ElrondPred::True {
+                    p2
+                } else if is_false(&p1) || p2 == ElrondPred::True || p1 == p2 {
+                    ElrondPred::True
+                } else if is_false(&p2) {
+                    not(p1)
+                } else {
+                    ElrondPred::Implies(Box::new(p1), Box::new(p2))
+                }
+            }
+            ElrondPred::Iff(p1, p2) => {
+                let (p1, p2) = (p1.simplify(), p2.simplify());
+                match (&p1, &p2) {
+                    _ if p1 == p2 => ElrondPred::True,
+                    (ElrondPred::True, _) => p2,
+                    (_, ElrondPred::True) => p1,
+                    _ if is_false(&p1) => not(p2),
+                    _ if is_false(&p2) => not(p1),
+                    _ => ElrondPred::Iff(Box::new(p1), Box::new(p2)),
+                }
+            }
+            ElrondPred::Ite(c, p1, p2) => {
+                let (c, p1, p2) = (c.simplify(), p1.simplify(), p2.simplify());
+                if c == ElrondPred::True || p1 == p2 {
+                    p1
+                } else if is_false(&c) {
+                    p2
+                } else if p1 == ElrondPred::True && is_false(&p2) {
+                    c
+                } else if is_false(&p1) && p2 == ElrondPred::True {
+                    not(c)
+                } else {
+                    ElrondPred::Ite(Box::new(c), Box::new(p1), Box::new(p2))
+                }
+            }
+        }
+    }
+
+    /// Simplifies and converts to `form`. CNF and DNF may be exponentially larger
+    /// than the input.
+    pub fn normalize(&self, form: NormalForm) -> ElrondPred {
+        let simplified = self.simplify();
+        match form {
+            NormalForm::Simplified => simplified,
+            NormalForm::IteFree => IteFree.fold_pred(simplified).simplify(),
+            NormalForm::Nnf => simplified.nnf(true).simplify(),
+            NormalForm::Cnf => {
+                from_clauses(clauses(&simplified.nnf(true).simplify(), true), true).simplify()
+            }
+            NormalForm::Dnf => {
+                from_clauses(clauses(&simplified.nnf(true).simplify(), false), false).simplify()
+            }
+        }
+    }
+
+    /// [`ElrondPred::normalize`], reporting the change in size.
+    pub fn simplification(&self, form: NormalForm) -> Simplification {
+        let pred = self.normalize(form);
+        Simplification {
+            size_before: self.size(),
+            size_after: pred.size(),
+            pred,
+        }
+    }
+
+    /// Negation normal form of `self` if `positive`, otherwise of its negation.
+    fn nnf(&self, positive: bool) -> ElrondPred {
+        let junction = |p_vec: Vec<ElrondPred>, and: bool| {
+            if and == positive {
+                ElrondPred::And(p_vec)
+            } else {
+                ElrondPred::Or(p_vec)
+            }
+        };
+        match self {
+            ElrondPred::True | ElrondPred::Atom(_) if positive => self.clone(),
+            ElrondPred::True | ElrondPred::Atom(_) => not(self.clone()),
+            ElrondPred::Not(p) => p.nnf(!positive),
+            ElrondPred::And(p_vec) => {
+                junction(p_vec.iter().map(|p| p.nnf(positive)).collect(), true)
+            }
+            ElrondPred::Or(p_vec) => {
+                junction(p_vec.iter().map(|p| p.nnf(positive)).collect(), false)
+            }
+            ElrondPred::Implies(p1, p2) => {
+                junction(vec![p1.nnf(!positive), p2.nnf(positive)], false)
+            }
+            ElrondPred::Iff(p1, p2) => ElrondPred::Or(vec![
+                ElrondPred::And(vec![p1.nnf(true), p2.nnf(positive)]),
+                ElrondPred::And(vec![p1.nnf(false), p2.nnf(!positive)]),
+            ]),
+            ElrondPred::Ite(c, p1, p2) => ElrondPred::Or(vec![
+                ElrondPred::And(vec![c.nnf(true), p1.nnf(positive)]),
+                ElrondPred::And(vec![c.nnf(false), p2.nnf(positive)]),
+            ]),
+        }
+    }
+}
+
+/// Replaces each `Ite` by a conjunction of implications.
+struct IteFree;
+
+impl Fold for IteFree {
+    fn fold_pred(&mut self, p: ElrondPred) -> ElrondPred {
+        match walk_pred_fold(self, p) {
+            ElrondPred::Ite(c, p1, p2) => {
+                let not_c = Box::new(not(*c.clone()));
+                ElrondPred::And(vec![
+                    ElrondPred::Implies(c, p1),
+                    ElrondPred::Implies(not_c, p2),
+                ])
+            }
+            p => p,
+        }
+    }
+}
+
+/// Simplifies an `And` (if `and`) or an `Or`.
+fn junction(p_vec: &[ElrondPred], and: bool) -> ElrondPred {
+    // The operand that is dropped, and the one that decides the junction on its own
+    let neutral = if and { ElrondPred::True } else { falsity() };
+    let absorbing = not(neutral.clone());
+    let mut operands: Vec<ElrondPred> = vec![];
+    for p in p_vec.iter().map(ElrondPred::simplify) {
+        let flattened = match p {
+            ElrondPred::And(inner) if and => inner,
+            ElrondPred::Or(inner) if !and => inner,
+            p => vec![p],
+        };
+        for p in flattened {
+            if p == absorbing {
+                return absorbing;
+            }
+            if p != neutral && !operands.contains(&p) {
+                operands.push(p);
+            }
+        }
+    }
+    match operands.len() {
+        0 => neutral,
+        1 => operands.pop().unwrap(),
+        _ if and => ElrondPred::And(operands),
+        _ => ElrondPred::Or(operands),
+    }
+}
+
+/// The clauses of an NNF predicate: disjunctions if `cnf`, otherwise conjunctions.
+fn clauses(pred: &ElrondPred, cnf: bool) -> Vec<Vec<ElrondPred>> {
+    match pred {
+        // The outer connective: collect each operand's clauses
+        ElrondPred::And(p_vec) if cnf => p_vec.iter().flat_map(|p| clauses(p, cnf)).collect(),
+        ElrondPred::Or(p_vec) if !cnf => p_vec.iter().flat_map(|p| clauses(p, cnf)).collect(),
+        // The inner connective: distribute over the operands' clauses
+        ElrondPred::And(p_vec) | ElrondPred::Or(p_vec) => {
+            p_vec.iter().fold(vec![vec![]], |acc, p| {
+                let cs = clauses(p, cnf);
+                acc.iter()
+                    .flat_map(|c1| {
+                        cs.iter()
+                            .map(move |c2| c1.iter().chain(c2).cloned().collect::<Vec<_>>())
+                    })
+                    .collect()
+            })
+        }
+        literal => vec![vec![literal.clone()]],
+    }
+}
+
+fn from_clauses(clauses: Vec<Vec<ElrondPred>>, cnf: bool) -> ElrondPred {
+    if cnf {
+        ElrondPred::And(clauses.into_iter().map(ElrondPred::Or).collect())
+    } else {
+        ElrondPred::Or(clauses.into_iter().map(ElrondPred::And).collect())
+    }
+}
+
+impl ElrondForallFormula {
+    pub fn normalize(&self, form: NormalForm) -> ElrondForallFormula {
+        ElrondForallFormula(self.0.clone(), self.1.normalize(form))
+    }
+}
+
+impl ElrondSpec {
+    pub fn normalize(&self, form: NormalForm) -> ElrondSpec {
+        ElrondSpec(self.0.clone(), self.1.normalize(form))
+    }
+}
+
+impl ElrondResult {
+    /// Normalizes every inferred spec; counterexamples are returned unchanged.
+    pub fn normalize(&self, form: NormalForm) -> ElrondResult {
+        match self {
+            ElrondResult::Cex(_) => self.clone(),
+            ElrondResult::Result(specs) => ElrondResult::Result(
+                specs
+                    .iter()
+                    .map(|(spec, name)| (spec.normalize(form), name.clone()))
+                    .collect(),
+            ),
+        }
+    }
+}
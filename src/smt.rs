@@ -0,0 +1,408 @@
+//! Export of specs and assertions to SMT-LIB2 scripts.
+//!
+//! Known predicates become uninterpreted functions named after the datatype they are
+//! applied to, such as `list_member` or `tree_member`, since SMT-LIB has no overloading.
+
+use std::{collections::BTreeSet, fmt::Display};
+
+use itertools::Itertools;
+
+use crate::{
+    ipc::{
+        ElrondAssertion, ElrondForallFormula, ElrondKnownPredicate, ElrondLiteral, ElrondPred,
+        ElrondSimpleExpr, ElrondSpec, ElrondTpedvar, ElrondType, FreeVar,
+    },
+    predicates::ElrondDatatype,
+    typecheck::type_of,
+};
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SmtOptions {
+    /// Declare `IntList` as an algebraic datatype and define the list predicates
+    /// with axioms. Without them the list predicates are left uninterpreted.
+    pub list_axioms: bool,
+    /// The datatype abstract types such as `Customstk.t` stand for. Defaults to lists.
+    pub generic: ElrondDatatype,
+}
+
+impl Default for SmtOptions {
+    fn default() -> Self {
+        SmtOptions {
+            list_axioms: false,
+            generic: ElrondDatatype::List,
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum SmtError {
+    /// A type with no SMT sort, such as a function type.
+    Unsupported(ElrondType),
+    /// An operator that is neither a comparison nor a known predicate.
+    UnknownOp(String),
+    /// A predicate applied to something other than a datatype it applies to.
+    IllTyped(String),
+    /// A list literal, which needs the list datatype of [`SmtOptions::list_axioms`].
+    ListLiteral(String),
+    /// A variable declared with two different sorts.
+    SortClash(String),
+}
+
+impl Display for SmtError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SmtError::Unsupported(ty) => write!(f, "type {ty} has no SMT sort"),
+            SmtError::UnknownOp(op) => write!(f, "unknown operator {op}"),
+            SmtError::IllTyped(e) => write!(f, "ill-typed predicate application {e}"),
+            SmtError::ListLiteral(e) => {
+                write!(
+                    f,
+                    "list literal {e} needs the list datatype, enable list_axioms"
+                )
+            }
+            SmtError::SortClash(name) => write!(f, "{name} is declared with two sorts"),
+        }
+    }
+}
+
+impl ElrondSpec {
+    /// Declares the spec's variables as constants and asserts its formula.
+    pub fn to_smt2(&self, options: &SmtOptions) -> Result<String, SmtError> {
+        let mut w = SmtWriter::new(options);
+        w.declare_vars(&self.0)?;
+        let formula = w.forall_formula(&self.1)?;
+        w.asserts.push(("spec".to_string(), formula));
+        w.finish(false)
+    }
+}
+
+impl ElrondForallFormula {
+    /// Asserts the formula, declaring the variables it leaves free as constants.
+    pub fn to_smt2(&self, options: &SmtOptions) -> Result<String, SmtError> {
+        let mut w = SmtWriter::new(options);
+        let formula = w.forall_formula(self)?;
+        w.asserts.push(("formula".to_string(), formula));
+        w.finish(false)
+    }
+}
+
+impl ElrondAssertion {
+    /// Asserts the pre spec and the negated post spec over the same argument
+    /// constants, so that `sat` means some input satisfying the pre spec violates
+    /// the post spec. This is the argument semantics of [`crate::explain`].
+    pub fn to_smt2(&self, options: &SmtOptions) -> Result<String, SmtError> {
+        let mut w = SmtWriter::new(options);
+        if let Some(pre) = &self.pre_spec {
+            w.declare_vars(&pre.0)?;
+            let formula = w.forall_formula(&pre.1)?;
+            w.asserts.push(("pre".to_string(), formula));
+        }
+        w.declare_vars(&self.post_spec.0)?;
+        let formula = w.forall_formula(&self.post_spec.1)?;
+        w.asserts
+            .push(("post".to_string(), format!("(not {formula})")));
+        w.finish(true)
+    }
+}
+
+struct SmtWriter<'a> {
+    options: &'a SmtOptions,
+    sorts: BTreeSet<&'static str>,
+    /// Function name, argument sorts.
+    funs: BTreeSet<(String, Vec<&'static str>)>,
+    consts: Vec<(String, &'static str)>,
+    /// Comment, term.
+    asserts: Vec<(String, String)>,
+}
+
+impl<'a> SmtWriter<'a> {
+    fn new(options: &'a SmtOptions) -> Self {
+        SmtWriter {
+            options,
+            sorts: BTreeSet::new(),
+            funs: BTreeSet::new(),
+            consts: vec![],
+            asserts: vec![],
+        }
+    }
+
+    fn datatype(&self, ty: &ElrondType) -> Option<ElrondDatatype> {
+        match ty {
+            ElrondType::Generic(_) => Some(self.options.generic),
+            _ => ElrondDatatype::from_type(ty),
+        }
+    }
+
+    fn sort(&mut self, ty: &ElrondType) -> Result<&'static str, SmtError> {
+        let sort = match ty {
+            ElrondType::Int => return Ok("Int"),
+            ElrondType::Bool => return Ok("Bool"),
+            _ => match self.datatype(ty) {
+                Some(ElrondDatatype::List) => "IntList",
+                Some(ElrondDatatype::Tree) => "IntTree",
+                None => return Err(SmtError::Unsupported(ty.clone())),
+            },
+        };
+        self.sorts.insert(sort);
+        Ok(sort)
+    }
+
+    fn declare_vars(&mut self, vars: &[ElrondTpedvar]) -> Result<(), SmtError> {
+        for ElrondTpedvar(ty, name) in vars {
+            self.declare_const(name, ty)?;
+        }
+        Ok(())
+    }
+
+    fn declare_const(&mut self, name: &str, ty: &ElrondType) -> Result<(), SmtError> {
+        let sort = self.sort(ty)?;
+        match self.consts.iter().find(|(n, _)| n == name) {
+            Some((_, s)) if *s != sort => Err(SmtError::SortClash(name.to_string())),
+            Some(_) => Ok(()),
+            None => {
+                self.consts.push((name.to_string(), sort));
+                Ok(())
+            }
+        }
+    }
+
+    fn forall_formula(&mut self, formula: &ElrondForallFormula) -> Result<String, SmtError> {
+        let ElrondForallFormula(vars, body) = formula;
+        let bound = vars.iter().map(|FreeVar(_, n)| n.as_str()).collect_vec();
+        let body = self.pred(body, &bound)?;
+        if vars.is_empty() {
+            return Ok(body);
+        }
+        let binders = vars
+            .iter()
+            .map(|FreeVar(ty, name)| Ok(format!("({} {})", symbol(name), self.sort(ty)?)))
+            .collect::<Result<Vec<_>, SmtError>>()?;
+        Ok(format!("(forall ({}) {body})", binders.join(" ")))
+    }
+
+    fn pred(&mut self, pred: &ElrondPred, bound: &[&str]) -> Result<String, SmtError> {
+        Ok(match pred {
+            ElrondPred::True => "true".to_string(),
+            ElrondPred::Atom(e) => self.expr(e, bound)?,
+            ElrondPred::Implies(p1, p2) => {
+                format!("(=> {} {})", self.pred(p1, bound)?, self.pred(p2, bound)?)
+            }
+            ElrondPred::Ite(c, p1, p2) => format!(
+                "(ite {} {} {})",
+                self.pred(c, bound)?,
+                self.pred(p1, bound)?,
+                self.pred(p2, bound)?
+            ),
+            ElrondPred::Not(p) => format!("(not {})", self.pred(p, bound)?),
+            ElrondPred::And(p_vec) => self.connective("and", "true", p_vec, bound)?,
+            ElrondPred::Or(p_vec) => self.connective("or", "false", p_vec, bound)?,
+            ElrondPred::Iff(p1, p2) => {
+                format!("(= {} {})", self.pred(p1, bound)?, self.pred(p2, bound)?)
+            }
+        })
+    }
+
+    /// `unit` is the value of the empty application.
+    fn connective(
+        &mut self,
+        op: &str,
+        unit: &str,
+        p_vec: &[ElrondPred],
+        bound: &[&str],
+    ) -> Result<String, SmtError> {
+        match p_vec {
+            [] => Ok(unit.to_string()),
+            [p] => self.pred(p, bound),
+            _ => {
+                let terms = p_vec
+                    .iter()
+                    .map(|p| self.pred(p, bound))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(format!("({op} {})", terms.join(" ")))
+            }
+        }
+    }
+
+    fn expr(&mut self, expr: &ElrondSimpleExpr, bound: &[&str]) -> Result<String, SmtError> {
+        match expr {
+            ElrondSimpleExpr::Literal(_, lit) => match lit {
+                ElrondLiteral::Int(i) => Ok(int(*i)),
+                ElrondLiteral::Bool(b) => Ok(b.to_string()),
+                ElrondLiteral::IntList(l) if self.options.list_axioms => {
+                    self.sorts.insert("IntList");
+                    Ok(l.iter().rev().fold("nil".to_string(), |tl, x| {
+                        format!("(cons {} {tl})", int(*x))
+                    }))
+                }
+                ElrondLiteral::IntList(_) => Err(SmtError::ListLiteral(expr.to_string())),
+            },
+            ElrondSimpleExpr::Var(ty, name) => {
+                if !bound.contains(&name.as_str()) {
+                    self.declare_const(name, ty)?;
+                }
+                Ok(symbol(name))
+            }
+            ElrondSimpleExpr::Op(_, op, args) => {
+                let terms = args
+                    .iter()
+                    .map(|a| self.expr(a, bound))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let comparison = match op.as_str() {
+                    "==" => Some("="),
+                    "<=" | ">=" | "<" | ">" => Some(op.as_str()),
+                    "!=" => Some("distinct"),
+                    _ => None,
+                };
+                if let Some(c) = comparison {
+                    return Ok(format!("({c} {})", terms.join(" ")));
+                }
+                let p: ElrondKnownPredicate = op
+                    .parse()
+                    .map_err(|_| SmtError::UnknownOp(op.to_string()))?;
+                let dt = args
+                    .first()
+                    .and_then(|a| self.datatype(&type_of(a)))
+                    .filter(|dt| p.signature().applies_to(*dt))
+                    .ok_or_else(|| SmtError::IllTyped(expr.to_string()))?;
+                let name = self.declare_predicate(p, dt)?;
+                Ok(format!("({name} {})", terms.join(" ")))
+            }
+            ElrondSimpleExpr::Tuple(_) => Err(SmtError::Unsupported(type_of(expr))),
+        }
+    }
+
+    fn declare_predicate(
+        &mut self,
+        p: ElrondKnownPredicate,
+        dt: ElrondDatatype,
+    ) -> Result<String, SmtError> {
+        let name = p
+            .engine_name(dt)
+            .ok_or_else(|| SmtError::IllTyped(p.to_string()))?
+            .to_string();
+        let sorts = p
+            .signature()
+            .arg_types(dt)
+            .unwrap_or_default()
+            .iter()
+            .map(|ty| self.sort(ty))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.funs.insert((name.clone(), sorts));
+        Ok(name)
+    }
+
+    fn finish(mut self, check_sat: bool) -> Result<String, SmtError> {
+        let list_axioms = self.options.list_axioms && self.sorts.contains("IntList");
+        let mut axioms = vec![];
+        if list_axioms {
+            // Axioms refer to the predicates they are defined with
+            let used = ElrondKnownPredicate::ALL
+                .into_iter()
+                .filter(|p| {
+                    p.engine_name(ElrondDatatype::List)
+                        .is_some_and(|name| self.funs.iter().any(|(n, _)| n == name))
+                })
+                .collect_vec();
+            for p in used {
+                for dep in list_axiom_deps(p) {
+                    self.declare_predicate(*dep, ElrondDatatype::List)?;
+                }
+            }
+            for (name, _) in &self.funs {
+                if let Some(a) = list_axioms_for(name) {
+                    axioms.push((name.clone(), a));
+                }
+            }
+        }
+
+        let mut out = String::new();
+        for sort in &self.sorts {
+            if *sort == "IntList" && list_axioms {
+                out +=
+                    "(declare-datatypes ((IntList 0)) (((nil) (cons (car Int) (cdr IntList)))))\n";
+            } else {
+                out += &format!("(declare-sort {sort} 0)\n");
+            }
+        }
+        for (name, sorts) in &self.funs {
+            out += &format!("(declare-fun {name} ({}) Bool)\n", sorts.join(" "));
+        }
+        for (name, axioms) in axioms {
+            out += &format!("; {name}\n");
+            for axiom in axioms {
+                out += &format!("(assert {axiom})\n");
+            }
+        }
+        for (name, sort) in &self.consts {
+            out += &format!("(declare-const {} {sort})\n", symbol(name));
+        }
+        for (comment, term) in &self.asserts {
+            out += &format!("; {comment}\n(assert {term})\n");
+        }
+        if check_sat {
+            out += "(check-sat)\n";
+        }
+        Ok(out)
+    }
+}
+
+fn list_axiom_deps(p: ElrondKnownPredicate) -> &'static [ElrondKnownPredicate] {
+    match p {
+        ElrondKnownPredicate::once | ElrondKnownPredicate::order => &[ElrondKnownPredicate::member],
+        ElrondKnownPredicate::sorted => &[ElrondKnownPredicate::head],
+        _ => &[],
+    }
+}
+
+/// Recursive definitions of the list predicates over `nil` and `cons`.
+fn list_axioms_for(name: &str) -> Option<[&'static str; 2]> {
+    Some(match name {
+        "list_member" => [
+            "(forall ((u Int)) (not (list_member nil u)))",
+            "(forall ((x Int) (l IntList) (u Int)) (= (list_member (cons x l) u) (or (= x u) (list_member l u))))",
+        ],
+        "list_head" => [
+            "(forall ((u Int)) (not (list_head nil u)))",
+            "(forall ((x Int) (l IntList) (u Int)) (= (list_head (cons x l) u) (= x u)))",
+        ],
+        "list_length" => [
+            "(forall ((n Int)) (= (list_length nil n) (= n 0)))",
+            "(forall ((x Int) (l IntList) (n Int)) (= (list_length (cons x l) n) (list_length l (- n 1))))",
+        ],
+        "list_once" => [
+            "(forall ((u Int)) (not (list_once nil u)))",
+            "(forall ((x Int) (l IntList) (u Int)) (= (list_once (cons x l) u) (ite (= x u) (not (list_member l u)) (list_once l u))))",
+        ],
+        "list_order" => [
+            "(forall ((u Int) (v Int)) (not (list_order nil u v)))",
+            "(forall ((x Int) (l IntList) (u Int) (v Int)) (= (list_order (cons x l) u v) (or (and (= x u) (list_member l v)) (list_order l u v))))",
+        ],
+        "list_sorted" => [
+            "(list_sorted nil)",
+            "(forall ((x Int) (l IntList)) (= (list_sorted (cons x l)) (and (list_sorted l) (forall ((y Int)) (=> (list_head l y) (<= x y))))))",
+        ],
+        _ => return None,
+    })
+}
+
+fn int(i: i64) -> String {
+    if i < 0 {
+        format!("(- {})", i.unsigned_abs())
+    } else {
+        i.to_string()
+    }
+}
+
+/// Names that are not simple SMT-LIB symbols are quoted.
+fn symbol(name: &str) -> String {
+    let simple = !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "~!@$%^&*_-+=<>.?/".contains(c));
+    if simple {
+        name.to_string()
+    } else {
+        format!("|{name}|")
+    }
+}
@@ -0,0 +1,428 @@
+//! Free variables, substitution and renaming of bound variables.
+//!
+//! `ElrondPred` and `Assertion` have no binders, so all their variables are
+//! free. Binders come from the `FreeVar`s of an `ElrondForallFormula` and the
+//! arguments of a spec; substituting under them renames any binder that would
+//! capture a variable of the substituted expressions.
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::Display,
+};
+
+use itertools::Itertools;
+
+use crate::{
+    ipc::{ElrondForallFormula, ElrondPred, ElrondSimpleExpr, ElrondSpec, ElrondTpedvar, FreeVar},
+    ipc_assertion::{Assertion, Spec, Tpedvar},
+    visit::{walk_expr, walk_expr_fold, Fold, Visitor},
+};
+
+/// Expressions to put in place of variables.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct Substitution(HashMap<String, ElrondSimpleExpr>);
+
+impl Substitution {
+    pub fn new() -> Self {
+        Substitution::default()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ElrondSimpleExpr> {
+        self.0.get(name)
+    }
+
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        e: ElrondSimpleExpr,
+    ) -> Option<ElrondSimpleExpr> {
+        self.0.insert(name.into(), e)
+    }
+
+    /// The variables of the substituted expressions, which binders must not capture.
+    fn range_vars(&self) -> BTreeSet<String> {
+        self.0
+            .values()
+            .flat_map(ElrondSimpleExpr::free_vars)
+            .collect()
+    }
+
+    /// The substitution without the variables in `names`, which are shadowed.
+    fn without(&self, names: &BTreeSet<String>) -> Substitution {
+        self.0
+            .iter()
+            .filter(|(n, _)| !names.contains(*n))
+            .map(|(n, e)| (n.clone(), e.clone()))
+            .collect()
+    }
+}
+
+impl FromIterator<(String, ElrondSimpleExpr)> for Substitution {
+    fn from_iter<T: IntoIterator<Item = (String, ElrondSimpleExpr)>>(iter: T) -> Self {
+        Substitution(iter.into_iter().collect())
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum SubstError {
+    /// A spec was instantiated with the wrong number of arguments.
+    Arity { expected: usize, found: usize },
+    /// A renamed variable is not bound by the formula.
+    NotBound(String),
+    /// Renaming `from` to `to` would capture a free variable or merge two binders.
+    Capture { from: String, to: String },
+}
+
+impl Display for SubstError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubstError::Arity { expected, found } => {
+                write!(f, "expected {expected} arguments, found {found}")
+            }
+            SubstError::NotBound(name) => write!(f, "{name} is not bound"),
+            SubstError::Capture { from, to } => {
+                write!(f, "renaming {from} to {to} would capture {to}")
+            }
+        }
+    }
+}
+
+/// A name like `base` that is not in `avoid`. Engine names such as `u_0` keep
+/// their stem and get the next free index.
+pub fn fresh_name(base: &str, avoid: &BTreeSet<String>) -> String {
+    let stem = match base.rsplit_once('_') {
+        Some((stem, index)) if !index.is_empty() && index.chars().all(|c| c.is_ascii_digit()) => {
+            stem
+        }
+        _ => base,
+    };
+    (0..)
+        .map(|i| format!("{stem}_{i}"))
+        .find(|name| !avoid.contains(name))
+        .unwrap()
+}
+
+#[derive(Default)]
+struct Vars(BTreeSet<String>);
+
+impl Visitor for Vars {
+    fn visit_expr(&mut self, e: &ElrondSimpleExpr) {
+        match e {
+            ElrondSimpleExpr::Var(_, name) => {
+                self.0.insert(name.clone());
+            }
+            e => walk_expr(self, e),
+        }
+    }
+
+    fn visit_name(&mut self, name: &str) {
+        self.0.insert(name.to_string());
+    }
+}
+
+/// Replaces variables by expressions, or by other variables of the same type.
+struct Subst<'a> {
+    exprs: &'a Substitution,
+    names: &'a HashMap<String, String>,
+}
+
+impl Fold for Subst<'_> {
+    fn fold_expr(&mut self, e: ElrondSimpleExpr) -> ElrondSimpleExpr {
+        match e {
+            ElrondSimpleExpr::Var(ty, name) => match (self.exprs.get(&name), self.names.get(&name))
+            {
+                (Some(e), _) => e.clone(),
+                (None, Some(to)) => ElrondSimpleExpr::Var(ty, to.clone()),
+                (None, None) => ElrondSimpleExpr::Var(ty, name),
+            },
+            e => walk_expr_fold(self, e),
+        }
+    }
+
+    fn fold_name(&mut self, name: String) -> String {
+        self.names.get(&name).cloned().unwrap_or(name)
+    }
+}
+
+impl ElrondSimpleExpr {
+    pub fn free_vars(&self) -> BTreeSet<String> {
+        let mut vars = Vars::default();
+        vars.visit_expr(self);
+        vars.0
+    }
+
+    pub fn substitute(&self, subst: &Substitution) -> ElrondSimpleExpr {
+        let names = HashMap::new();
+        Subst {
+            exprs: subst,
+            names: &names,
+        }
+        .fold_expr(self.clone())
+    }
+}
+
+impl ElrondPred {
+    /// Every variable of the predicate, since predicates bind none.
+    pub fn free_vars(&self) -> BTreeSet<String> {
+        let mut vars = Vars::default();
+        vars.visit_pred(self);
+        vars.0
+    }
+
+    pub fn substitute(&self, subst: &Substitution) -> ElrondPred {
+        self.rename_and_substitute(subst, &HashMap::new())
+    }
+
+    /// Renames variables. Both are applied simultaneously, so `names` may swap variables.
+    pub fn rename(&self, names: &HashMap<String, String>) -> ElrondPred {
+        self.rename_and_substitute(&Substitution::new(), names)
+    }
+
+    fn rename_and_substitute(
+        &self,
+        exprs: &Substitution,
+        names: &HashMap<String, String>,
+    ) -> ElrondPred {
+        Subst { exprs, names }.fold_pred(self.clone())
+    }
+}
+
+/// Substitutes into `body` under `binders`, renaming the binders that would capture
+/// a variable of `subst`. Returns the new binder names, in order, and the new body.
+fn substitute_under(
+    binders: &[&String],
+    body: &ElrondPred,
+    subst: &Substitution,
+) -> (Vec<String>, ElrondPred) {
+    let bound: BTreeSet<String> = binders.iter().map(|b| b.to_string()).collect();
+    let body_vars = body.free_vars();
+    let subst = subst.without(&bound);
+    let range_vars = subst.range_vars();
+    let mut avoid: BTreeSet<String> = body_vars.union(&range_vars).cloned().collect();
+    avoid.extend(bound.iter().cloned());
+    let mut names = HashMap::new();
+    let binders = binders
+        .iter()
+        .map(|b| {
+            if range_vars.contains(*b) {
+                let to = fresh_name(b, &avoid);
+                avoid.insert(to.clone());
+                names.insert(b.to_string(), to.clone());
+                to
+            } else {
+                b.to_string()
+            }
+        })
+        .collect();
+    (binders, body.rename_and_substitute(&subst, &names))
+}
+
+/// Checks that `renaming` only renames `binders` and captures nothing free, and
+/// returns it as a map.
+fn check_renaming(
+    binders: &[&String],
+    free: &BTreeSet<String>,
+    renaming: &[(&str, &str)],
+) -> Result<HashMap<String, String>, SubstError> {
+    let names: HashMap<String, String> = renaming
+        .iter()
+        .map(|(from, to)| (from.to_string(), to.to_string()))
+        .collect();
+    if let Some(from) = names.keys().find(|n| !binders.contains(n)) {
+        return Err(SubstError::NotBound(from.clone()));
+    }
+    let mut seen = BTreeSet::new();
+    for b in binders {
+        let to = names.get(*b).unwrap_or(b);
+        if free.contains(to) || !seen.insert(to) {
+            return Err(SubstError::Capture {
+                from: b.to_string(),
+                to: to.clone(),
+            });
+        }
+    }
+    Ok(names)
+}
+
+impl ElrondForallFormula {
+    pub fn bound_vars(&self) -> BTreeSet<String> {
+        self.0.iter().map(|FreeVar(_, name)| name.clone()).collect()
+    }
+
+    pub fn free_vars(&self) -> BTreeSet<String> {
+        let bound = self.bound_vars();
+        self.1
+            .free_vars()
+            .into_iter()
+            .filter(|n| !bound.contains(n))
+            .collect()
+    }
+
+    /// Substitutes the free variables, renaming quantified ones out of the way.
+    pub fn substitute(&self, subst: &Substitution) -> ElrondForallFormula {
+        let ElrondForallFormula(free_vars, body) = self;
+        let binders = free_vars.iter().map(|FreeVar(_, n)| n).collect_vec();
+        let (names, body) = substitute_under(&binders, body, subst);
+        let free_vars = free_vars
+            .iter()
+            .zip(names)
+            .map(|(FreeVar(ty, _), name)| FreeVar(ty.clone(), name))
+            .collect();
+        ElrondForallFormula(free_vars, body)
+    }
+
+    /// Renames quantified variables, e.g. the engine's `u_0` to a user's `x`.
+    pub fn alpha_rename(
+        &self,
+        renaming: &[(&str, &str)],
+    ) -> Result<ElrondForallFormula, SubstError> {
+        let ElrondForallFormula(free_vars, body) = self;
+        let binders = free_vars.iter().map(|FreeVar(_, n)| n).collect_vec();
+        let names = check_renaming(&binders, &self.free_vars(), renaming)?;
+        let free_vars = free_vars
+            .iter()
+            .map(|FreeVar(ty, n)| FreeVar(ty.clone(), names.get(n).unwrap_or(n).clone()))
+            .collect();
+        Ok(ElrondForallFormula(free_vars, body.rename(&names)))
+    }
+}
+
+impl ElrondSpec {
+    /// The arguments and the quantified variables.
+    pub fn bound_vars(&self) -> BTreeSet<String> {
+        let ElrondSpec(args, formula) = self;
+        let mut bound = formula.bound_vars();
+        bound.extend(args.iter().map(|ElrondTpedvar(_, name)| name.clone()));
+        bound
+    }
+
+    pub fn free_vars(&self) -> BTreeSet<String> {
+        let bound = self.bound_vars();
+        self.1
+             .1
+            .free_vars()
+            .into_iter()
+            .filter(|n| !bound.contains(n))
+            .collect()
+    }
+
+    /// Substitutes the free variables, renaming arguments and quantified variables
+    /// out of the way.
+    pub fn substitute(&self, subst: &Substitution) -> ElrondSpec {
+        let ElrondSpec(args, ElrondForallFormula(free_vars, body)) = self;
+        let binders = args
+            .iter()
+            .map(|ElrondTpedvar(_, n)| n)
+            .chain(free_vars.iter().map(|FreeVar(_, n)| n))
+            .collect_vec();
+        let (mut names, body) = substitute_under(&binders, body, subst);
+        let free_names = names.split_off(args.len());
+        let args = args
+            .iter()
+            .zip(names)
+            .map(|(ElrondTpedvar(ty, _), name)| ElrondTpedvar(ty.clone(), name))
+            .collect();
+        let free_vars = free_vars
+            .iter()
+            .zip(free_names)
+            .map(|(FreeVar(ty, _), name)| FreeVar(ty.clone(), name))
+            .collect();
+        ElrondSpec(args, ElrondForallFormula(free_vars, body))
+    }
+
+    /// The formula with `actuals` in place of the arguments, as when a call site
+    /// instantiates a callee's spec.
+    pub fn instantiate(
+        &self,
+        actuals: &[ElrondSimpleExpr],
+    ) -> Result<ElrondForallFormula, SubstError> {
+        let ElrondSpec(args, formula) = self;
+        if args.len() != actuals.len() {
+            return Err(SubstError::Arity {
+                expected: args.len(),
+                found: actuals.len(),
+            });
+        }
+        let subst = args
+            .iter()
+            .zip(actuals)
+            .map(|(ElrondTpedvar(_, name), e)| (name.clone(), e.clone()))
+            .collect();
+        Ok(formula.substitute(&subst))
+    }
+
+    /// Renames arguments and quantified variables.
+    pub fn alpha_rename(&self, renaming: &[(&str, &str)]) -> Result<ElrondSpec, SubstError> {
+        let ElrondSpec(args, ElrondForallFormula(free_vars, body)) = self;
+        let binders = args
+            .iter()
+            .map(|ElrondTpedvar(_, n)| n)
+            .chain(free_vars.iter().map(|FreeVar(_, n)| n))
+            .collect_vec();
+        let names = check_renaming(&binders, &self.free_vars(), renaming)?;
+        let rename = |n: &String| names.get(n).unwrap_or(n).clone();
+        let args = args
+            .iter()
+            .map(|ElrondTpedvar(ty, n)| ElrondTpedvar(ty.clone(), rename(n)))
+            .collect();
+        let free_vars = free_vars
+            .iter()
+            .map(|FreeVar(ty, n)| FreeVar(ty.clone(), rename(n)))
+            .collect();
+        Ok(ElrondSpec(
+            args,
+            ElrondForallFormula(free_vars, body.rename(&names)),
+        ))
+    }
+}
+
+impl Assertion {
+    /// Every variable of the assertion, since assertions bind none.
+    pub fn free_vars(&self) -> BTreeSet<String> {
+        let mut vars = Vars::default();
+        vars.visit_assertion(self);
+        vars.0
+    }
+
+    /// Renames variables. Assertions only have variables as arguments, so this is
+    /// also their substitution.
+    pub fn rename(&self, names: &HashMap<String, String>) -> Assertion {
+        Subst {
+            exprs: &Substitution::new(),
+            names,
+        }
+        .fold_assertion(self.clone())
+    }
+}
+
+impl Spec {
+    pub fn bound_vars(&self) -> BTreeSet<String> {
+        self.0.iter().map(|Tpedvar(_, name)| name.clone()).collect()
+    }
+
+    pub fn free_vars(&self) -> BTreeSet<String> {
+        let bound = self.bound_vars();
+        self.1
+            .free_vars()
+            .into_iter()
+            .filter(|n| !bound.contains(n))
+            .collect()
+    }
+
+    /// The assertion with the variables `actuals` in place of the arguments.
+    pub fn instantiate(&self, actuals: &[&str]) -> Result<Assertion, SubstError> {
+        let Spec(args, assertion) = self;
+        if args.len() != actuals.len() {
+            return Err(SubstError::Arity {
+                expected: args.len(),
+                found: actuals.len(),
+            });
+        }
+        let names = args
+            .iter()
+            .zip(actuals)
+            .map(|(Tpedvar(_, name), actual)| (name.clone(), actual.to_string()))
+            .collect();
+        Ok(assertion.rename(&names))
+    }
+}
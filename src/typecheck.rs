@@ -0,0 +1,540 @@
+//! Type inference for assertions read by [`crate::program_parser`].
+//!
+//! The parser cannot know the types of variables and ops, so it stamps them with
+//! [`UNKNOWN_TYPE`]. Checking fills them in from the spec's arguments and the
+//! predicate signatures, and replaces abstract types such as `Customstk.t` by the
+//! datatype they stand for, as lowering does, so only fully-typed expressions
+//! reach the engine.
+
+use std::{collections::HashMap, fmt::Display};
+
+use itertools::Itertools;
+
+use crate::{
+    ipc::{
+        ElrondAssertion, ElrondForallFormula, ElrondKnownPredicate, ElrondLiteral, ElrondPred,
+        ElrondSimpleExpr, ElrondSpec, ElrondTpedvar, ElrondType, FreeVar,
+    },
+    lowering::{AbstractTypes, LoweringError},
+    predicates::ElrondDatatype,
+    program_parser::SpannedAssertionParser,
+    visit::{walk_expr_fold, Fold},
+};
+
+/// The placeholder type the parser gives variables and ops.
+pub const UNKNOWN_TYPE: &str = "Unknown ParserType";
+
+/// A byte range of the parsed source.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    /// The 1-based line and column of the start of the span in `source`.
+    pub fn line_col(&self, source: &str) -> (usize, usize) {
+        let before = &source[..self.start.min(source.len())];
+        let line = before.matches('\n').count() + 1;
+        let column = before.chars().rev().take_while(|c| *c != '\n').count() + 1;
+        (line, column)
+    }
+}
+
+impl Display for Span {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/// The spans of the parsed nodes of an assertion, keyed by their path: the index of
+/// the child taken at each level. The pre spec is child 0 and the post spec child 1,
+/// and below them operands and arguments count from 0 in source order.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct SpanMap(HashMap<Vec<usize>, Span>);
+
+impl SpanMap {
+    /// A node at `span` whose children have no spans yet.
+    pub fn leaf(span: Span) -> Self {
+        SpanMap(HashMap::from([(vec![], span)]))
+    }
+
+    /// Adds the spans of the `i`th child.
+    pub fn child(mut self, i: usize, child: SpanMap) -> Self {
+        for (mut path, span) in child.0 {
+            path.insert(0, i);
+            self.0.insert(path, span);
+        }
+        self
+    }
+
+    /// A node without a span of its own and two children.
+    pub fn pair(first: SpanMap, second: SpanMap) -> Self {
+        SpanMap::default().child(0, first).child(1, second)
+    }
+
+    pub fn get(&self, path: &[usize]) -> Option<Span> {
+        self.0.get(path).copied()
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum TypeErrorKind {
+    /// The source does not parse.
+    Syntax(String),
+    /// A variable that is not an argument of its spec.
+    UnboundVariable(String),
+    /// An op that is neither a known predicate nor a comparison.
+    UnknownOp(String),
+    Arity {
+        op: String,
+        expected: usize,
+        found: usize,
+    },
+    /// An argument of `op`, or the atom itself if `op` is `None`, has the wrong type.
+    Mismatch {
+        op: Option<String>,
+        expected: String,
+        found: ElrondType,
+    },
+    /// An abstract type that is not named in the [`AbstractTypes`] and whose uses fit
+    /// `candidates`, which is not exactly one datatype.
+    UnresolvedType {
+        ty: String,
+        candidates: Vec<ElrondDatatype>,
+    },
+}
+
+impl Display for TypeErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TypeErrorKind::Syntax(e) => write!(f, "syntax error: {e}"),
+            TypeErrorKind::UnboundVariable(name) => write!(f, "unbound variable {name}"),
+            TypeErrorKind::UnknownOp(op) => write!(f, "unknown predicate or operator {op}"),
+            TypeErrorKind::Arity {
+                op,
+                expected,
+                found,
+            } => write!(f, "{op} expects {expected} arguments, found {found}"),
+            TypeErrorKind::Mismatch {
+                op: Some(op),
+                expected,
+                found,
+            } => write!(f, "{op} expects {expected}, found {found}"),
+            TypeErrorKind::Mismatch {
+                op: None,
+                expected,
+                found,
+            } => write!(f, "an atom must be {expected}, found {found}"),
+            TypeErrorKind::UnresolvedType { ty, candidates } => {
+                let error = LoweringError::UnresolvedType {
+                    ty: ty.clone(),
+                    candidates: candidates.clone(),
+                };
+                write!(f, "{error}")
+            }
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct TypeError {
+    /// Where the error is, if the assertion came from source.
+    pub span: Option<Span>,
+    pub kind: TypeErrorKind,
+}
+
+impl Display for TypeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.span {
+            Some(span) => write!(f, "{span}: {}", self.kind),
+            None => write!(f, "{}", self.kind),
+        }
+    }
+}
+
+impl TypeError {
+    /// The error prefixed with its line and column in `source`.
+    pub fn display_in(&self, source: &str) -> String {
+        match self.span {
+            Some(span) => {
+                let (line, column) = span.line_col(source);
+                format!("{line}:{column}: {}", self.kind)
+            }
+            None => self.kind.to_string(),
+        }
+    }
+}
+
+/// Parses and type-checks an assertion in the syntax of `program_parser`, resolving
+/// abstract types from the predicates applied to them.
+pub fn parse_typed(source: &str) -> Result<ElrondAssertion, Vec<TypeError>> {
+    parse_typed_with(source, &AbstractTypes::default())
+}
+
+/// Parses and type-checks an assertion, giving abstract types named in `types` their
+/// datatype there.
+pub fn parse_typed_with(
+    source: &str,
+    types: &AbstractTypes,
+) -> Result<ElrondAssertion, Vec<TypeError>> {
+    let (assertion, spans) = SpannedAssertionParser::new().parse(source).map_err(|e| {
+        let span = match &e {
+            lalrpop_util::ParseError::InvalidToken { location }
+            | lalrpop_util::ParseError::UnrecognizedEof { location, .. } => Some(Span {
+                start: *location,
+                end: *location,
+            }),
+            lalrpop_util::ParseError::UnrecognizedToken {
+                token: (start, _, end),
+                ..
+            }
+            | lalrpop_util::ParseError::ExtraToken {
+                token: (start, _, end),
+            } => Some(Span {
+                start: *start,
+                end: *end,
+            }),
+            lalrpop_util::ParseError::User { .. } => None,
+        };
+        vec![TypeError {
+            span,
+            kind: TypeErrorKind::Syntax(e.to_string()),
+        }]
+    })?;
+    check_assertion(&assertion, &spans, types)
+}
+
+impl ElrondAssertion {
+    /// Infers the types of every variable and op. Errors carry no spans, since the
+    /// AST has none; use [`parse_typed`] to check source text.
+    pub fn typecheck(&self) -> Result<ElrondAssertion, Vec<TypeError>> {
+        self.typecheck_with(&AbstractTypes::default())
+    }
+
+    /// Like [`ElrondAssertion::typecheck`], with abstract types named in `types`
+    /// given their datatype there.
+    pub fn typecheck_with(&self, types: &AbstractTypes) -> Result<ElrondAssertion, Vec<TypeError>> {
+        check_assertion(self, &SpanMap::default(), types)
+    }
+}
+
+fn check_assertion(
+    assertion: &ElrondAssertion,
+    spans: &SpanMap,
+    types: &AbstractTypes,
+) -> Result<ElrondAssertion, Vec<TypeError>> {
+    let mut checker = Checker {
+        env: HashMap::new(),
+        spans,
+        path: vec![],
+        errors: vec![],
+        types,
+        uses: vec![],
+    };
+    let pre_spec = assertion
+        .pre_spec
+        .as_ref()
+        .map(|pre| checker.child(0, |checker| checker.spec(pre.clone())));
+    let post_spec = checker.child(1, |checker| checker.spec(assertion.post_spec.clone()));
+    if !checker.errors.is_empty() {
+        return Err(checker.errors);
+    }
+
+    let mut resolved = Resolve(types.clone());
+    let mut errors = vec![];
+    for (ty, candidates) in checker.uses {
+        // Like the SMT export and the generator, take an abstract type nothing
+        // constrains to be a list
+        let candidates = candidates.unwrap_or_else(|| vec![ElrondDatatype::List]);
+        match candidates.as_slice() {
+            [dt] => resolved.0 .0.push((ty, *dt)),
+            _ => errors.push(TypeError {
+                span: None,
+                kind: TypeErrorKind::UnresolvedType { ty, candidates },
+            }),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    let mut names = resolved.0.clone();
+    for (ty, dt) in &assertion.types.0 {
+        if names.get(ty).is_none() {
+            names.0.push((ty.clone(), *dt));
+        }
+    }
+    Ok(ElrondAssertion {
+        preds: assertion.preds.clone(),
+        pre_spec: pre_spec.map(|pre| resolved.spec(pre)),
+        post_spec: resolved.spec(post_spec),
+        types: names,
+    })
+}
+
+struct Checker<'a> {
+    env: HashMap<String, ElrondType>,
+    spans: &'a SpanMap,
+    /// The path in `spans` of the node being checked.
+    path: Vec<usize>,
+    errors: Vec<TypeError>,
+    types: &'a AbstractTypes,
+    /// The datatypes each abstract type missing from `types` may still be, or
+    /// `None` while no predicate has been applied to its values.
+    uses: Vec<(String, Option<Vec<ElrondDatatype>>)>,
+}
+
+fn unknown() -> ElrondType {
+    ElrondType::Generic(UNKNOWN_TYPE.to_string())
+}
+
+fn is_unknown(ty: &ElrondType) -> bool {
+    matches!(ty, ElrondType::Generic(s) if s == UNKNOWN_TYPE)
+}
+
+/// The type an expression was annotated with; tuples take their components' types.
+pub fn type_of(e: &ElrondSimpleExpr) -> ElrondType {
+    match e {
+        ElrondSimpleExpr::Literal(ty, _)
+        | ElrondSimpleExpr::Var(ty, _)
+        | ElrondSimpleExpr::Op(ty, _, _) => ty.clone(),
+        ElrondSimpleExpr::Tuple(t) => ElrondType::Tuple(t.iter().map(type_of).collect()),
+    }
+}
+
+impl Checker<'_> {
+    fn spec(&mut self, ElrondSpec(args, formula): ElrondSpec) -> ElrondSpec {
+        let ElrondForallFormula(free_vars, body) = formula;
+        let abstract_types = args
+            .iter()
+            .map(|ElrondTpedvar(ty, _)| ty)
+            .chain(free_vars.iter().map(|FreeVar(ty, _)| ty));
+        for ty in abstract_types {
+            if let ElrondType::Generic(name) = ty {
+                if self.types.get(name).is_none() && !self.uses.iter().any(|(n, _)| n == name) {
+                    self.uses.push((name.clone(), None));
+                }
+            }
+        }
+        self.env = args
+            .iter()
+            .map(|ElrondTpedvar(ty, name)| (name.clone(), ty.clone()))
+            .chain(
+                free_vars
+                    .iter()
+                    .map(|FreeVar(ty, name)| (name.clone(), ty.clone())),
+            )
+            .collect();
+        let body = self.fold_pred(body);
+        ElrondSpec(args, ElrondForallFormula(free_vars, body))
+    }
+
+    /// Whether `ty` may be the datatype argument of a predicate over `datatypes`.
+    /// Abstract types missing from the [`AbstractTypes`] are narrowed to the
+    /// datatypes every such predicate accepts.
+    fn is_datatype(&mut self, ty: &ElrondType, datatypes: &[ElrondDatatype]) -> bool {
+        let ElrondType::Generic(name) = ty else {
+            return ElrondDatatype::from_type(ty).is_some_and(|dt| datatypes.contains(&dt));
+        };
+        if let Some(dt) = self.types.get(name) {
+            return datatypes.contains(&dt);
+        }
+        match self.uses.iter_mut().find(|(n, _)| n == name) {
+            Some((_, candidates)) => {
+                candidates
+                    .get_or_insert_with(|| datatypes.to_vec())
+                    .retain(|dt| datatypes.contains(dt));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Runs `f` on the `i`th child of the node being checked.
+    fn child<T>(&mut self, i: usize, f: impl FnOnce(&mut Self) -> T) -> T {
+        self.path.push(i);
+        let result = f(self);
+        self.path.pop();
+        result
+    }
+
+    fn span(&self) -> Option<Span> {
+        self.spans.get(&self.path)
+    }
+
+    /// Folds each of `children` as the child at its index.
+    fn fold_children<T>(&mut self, children: Vec<T>, fold: fn(&mut Self, T) -> T) -> Vec<T> {
+        children
+            .into_iter()
+            .enumerate()
+            .map(|(i, c)| self.child(i, |checker| fold(checker, c)))
+            .collect()
+    }
+
+    fn error(&mut self, span: Option<Span>, kind: TypeErrorKind) {
+        self.errors.push(TypeError { span, kind });
+    }
+
+    /// Checks `found` against `expected`, unless an earlier error made it unknown.
+    fn expect(
+        &mut self,
+        span: Option<Span>,
+        op: Option<&str>,
+        ok: bool,
+        expected: impl Display,
+        found: &ElrondType,
+    ) {
+        if !ok && !is_unknown(found) {
+            self.error(
+                span,
+                TypeErrorKind::Mismatch {
+                    op: op.map(str::to_string),
+                    expected: expected.to_string(),
+                    found: found.clone(),
+                },
+            )
+        }
+    }
+
+    /// The type of applying `op` to arguments of types `args`.
+    fn op_type(&mut self, span: Option<Span>, op: &str, args: &[ElrondType]) -> ElrondType {
+        let arity = |checker: &mut Self, expected: usize| {
+            if args.len() != expected {
+                checker.error(
+                    span,
+                    TypeErrorKind::Arity {
+                        op: op.to_string(),
+                        expected,
+                        found: args.len(),
+                    },
+                );
+            }
+            args.len() == expected
+        };
+        match op {
+            "==" | "!=" => {
+                if arity(self, 2) && !is_unknown(&args[0]) {
+                    let ok = args[0] == args[1];
+                    self.expect(span, Some(op), ok, &args[0], &args[1]);
+                }
+            }
+            "<=" | ">=" | "<" | ">" => {
+                if arity(self, 2) {
+                    for ty in args {
+                        self.expect(span, Some(op), *ty == ElrondType::Int, "int", ty);
+                    }
+                }
+            }
+            _ => match op.parse::<ElrondKnownPredicate>() {
+                Ok(pred) => {
+                    let signature = pred.signature();
+                    if arity(self, signature.arity()) {
+                        let datatypes = signature.datatypes.iter().join(" or ");
+                        let ok = self.is_datatype(&args[0], signature.datatypes);
+                        self.expect(span, Some(op), ok, datatypes, &args[0]);
+                        for (expected, found) in signature.args.iter().zip(&args[1..]) {
+                            self.expect(span, Some(op), expected == found, expected, found);
+                        }
+                    }
+                }
+                Err(_) => self.error(span, TypeErrorKind::UnknownOp(op.to_string())),
+            },
+        }
+        ElrondType::Bool
+    }
+}
+
+impl Fold for Checker<'_> {
+    /// Visits the children by hand to keep `path` pointing at the node checked.
+    fn fold_pred(&mut self, p: ElrondPred) -> ElrondPred {
+        let fold = |checker: &mut Self, i, p: Box<ElrondPred>| {
+            Box::new(checker.child(i, |checker| checker.fold_pred(*p)))
+        };
+        match p {
+            ElrondPred::True => ElrondPred::True,
+            ElrondPred::Atom(e) => self.child(0, |checker| {
+                let e = checker.fold_expr(e);
+                let ty = type_of(&e);
+                if let ElrondSimpleExpr::Var(..) = e {
+                    let span = checker.span();
+                    checker.expect(span, None, ty == ElrondType::Bool, "bool", &ty);
+                }
+                ElrondPred::Atom(e)
+            }),
+            ElrondPred::Implies(p1, p2) => {
+                ElrondPred::Implies(fold(self, 0, p1), fold(self, 1, p2))
+            }
+            ElrondPred::Iff(p1, p2) => ElrondPred::Iff(fold(self, 0, p1), fold(self, 1, p2)),
+            ElrondPred::Ite(p1, p2, p3) => {
+                ElrondPred::Ite(fold(self, 0, p1), fold(self, 1, p2), fold(self, 2, p3))
+            }
+            ElrondPred::Not(p) => ElrondPred::Not(fold(self, 0, p)),
+            ElrondPred::And(p_vec) => ElrondPred::And(self.fold_children(p_vec, Self::fold_pred)),
+            ElrondPred::Or(p_vec) => ElrondPred::Or(self.fold_children(p_vec, Self::fold_pred)),
+        }
+    }
+
+    fn fold_expr(&mut self, e: ElrondSimpleExpr) -> ElrondSimpleExpr {
+        let span = self.span();
+        match e {
+            ElrondSimpleExpr::Var(_, name) => {
+                let ty = self.env.get(&name).cloned().unwrap_or_else(|| {
+                    self.error(span, TypeErrorKind::UnboundVariable(name.clone()));
+                    unknown()
+                });
+                ElrondSimpleExpr::Var(ty, name)
+            }
+            ElrondSimpleExpr::Op(_, op, args) => {
+                let args = self.fold_children(args, Self::fold_expr);
+                let arg_types = args.iter().map(type_of).collect_vec();
+                let ty = self.op_type(span, &op, &arg_types);
+                ElrondSimpleExpr::Op(ty, op, args)
+            }
+            ElrondSimpleExpr::Literal(_, lit) => {
+                let ty = match lit {
+                    ElrondLiteral::Int(_) => ElrondType::Int,
+                    ElrondLiteral::Bool(_) => ElrondType::Bool,
+                    ElrondLiteral::IntList(_) => ElrondType::IntList,
+                };
+                ElrondSimpleExpr::Literal(ty, lit)
+            }
+            ElrondSimpleExpr::Tuple(args) => {
+                ElrondSimpleExpr::Tuple(self.fold_children(args, Self::fold_expr))
+            }
+        }
+    }
+}
+
+/// Replaces every abstract type by its datatype.
+struct Resolve(AbstractTypes);
+
+impl Resolve {
+    fn ty(&self, ty: ElrondType) -> ElrondType {
+        match &ty {
+            ElrondType::Generic(name) => self.0.get(name).map_or(ty, |dt| dt.elrond_type()),
+            _ => ty,
+        }
+    }
+
+    fn spec(&mut self, ElrondSpec(args, formula): ElrondSpec) -> ElrondSpec {
+        let ElrondForallFormula(free_vars, body) = formula;
+        let args = args
+            .into_iter()
+            .map(|ElrondTpedvar(ty, name)| ElrondTpedvar(self.ty(ty), name))
+            .collect();
+        let free_vars = free_vars
+            .into_iter()
+            .map(|FreeVar(ty, name)| FreeVar(self.ty(ty), name))
+            .collect();
+        ElrondSpec(args, ElrondForallFormula(free_vars, self.fold_pred(body)))
+    }
+}
+
+impl Fold for Resolve {
+    fn fold_expr(&mut self, e: ElrondSimpleExpr) -> ElrondSimpleExpr {
+        match walk_expr_fold(self, e) {
+            ElrondSimpleExpr::Var(ty, name) => ElrondSimpleExpr::Var(self.ty(ty), name),
+            e => e,
+        }
+    }
+}
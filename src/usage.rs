@@ -0,0 +1,198 @@
+//! Checks that specs only apply the predicates they declare.
+//!
+//! Elrond infers specs over the predicates in `ArwenSetup::predicates`, and an
+//! assertion file lists its own in `preds`; applying any other predicate gives
+//! confusing results rather than an error, so they are caught here.
+
+use std::fmt::Display;
+
+use crate::{
+    ipc::{
+        ElrondAssertion, ElrondKnownPredicate, ElrondPredicates, ElrondResult, ElrondSimpleExpr,
+        ElrondSpec,
+    },
+    ipc_assertion::{Assertion, AssertionFile},
+    visit::{walk_assertion, walk_expr, Visitor},
+};
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum PredicateIssue {
+    /// A predicate applied `count` times in `spec` that is not declared.
+    Undeclared {
+        spec: String,
+        predicate: ElrondKnownPredicate,
+        count: usize,
+    },
+    /// A named operator applied in `spec` that is not a known predicate.
+    Unknown {
+        spec: String,
+        name: String,
+        count: usize,
+    },
+    /// A declared predicate that no spec applies.
+    Unused(ElrondKnownPredicate),
+}
+
+impl Display for PredicateIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PredicateIssue::Undeclared {
+                spec,
+                predicate,
+                count,
+            } => write!(
+                f,
+                "{spec} applies {predicate} {}, but it is not declared",
+                times(*count)
+            ),
+            PredicateIssue::Unknown { spec, name, count } => {
+                write!(
+                    f,
+                    "{spec} applies unknown predicate {name} {}",
+                    times(*count)
+                )
+            }
+            PredicateIssue::Unused(predicate) => {
+                write!(f, "{predicate} is declared but never used")
+            }
+        }
+    }
+}
+
+fn times(count: usize) -> String {
+    match count {
+        1 => "once".to_string(),
+        n => format!("{n} times"),
+    }
+}
+
+/// The predicate applications of one spec, in order of first appearance, with
+/// how often each occurs.
+#[derive(Default)]
+struct Applications(Vec<(Result<ElrondKnownPredicate, String>, usize)>);
+
+impl Applications {
+    fn add(&mut self, applied: Result<ElrondKnownPredicate, String>) {
+        match self.0.iter_mut().find(|(a, _)| *a == applied) {
+            Some((_, count)) => *count += 1,
+            None => self.0.push((applied, 1)),
+        }
+    }
+
+    fn of_spec(spec: &ElrondSpec) -> Self {
+        let mut applications = Applications::default();
+        applications.visit_pred(&spec.1 .1);
+        applications
+    }
+
+    fn of_assertion(assertion: &Assertion) -> Self {
+        let mut applications = Applications::default();
+        applications.visit_assertion(assertion);
+        applications
+    }
+}
+
+impl Visitor for Applications {
+    fn visit_expr(&mut self, e: &ElrondSimpleExpr) {
+        // Predicates are named; symbols such as `==` and `+` are builtin operators
+        if let ElrondSimpleExpr::Op(_, op, _) = e {
+            if op.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+                self.add(op.parse().map_err(|_| op.clone()));
+            }
+        }
+        walk_expr(self, e)
+    }
+
+    fn visit_assertion(&mut self, a: &Assertion) {
+        if let Assertion::Predicate(p, _) = a {
+            self.add(Ok(*p));
+        }
+        walk_assertion(self, a)
+    }
+}
+
+/// Checks the applications in each named spec against `declared`. Predicates in
+/// `allowed` may also be applied, but are not reported when unused.
+fn check(
+    declared: &[ElrondKnownPredicate],
+    allowed: &[ElrondKnownPredicate],
+    specs: Vec<(String, Applications)>,
+) -> Vec<PredicateIssue> {
+    let mut issues = vec![];
+    for (spec, applications) in &specs {
+        for (applied, count) in &applications.0 {
+            match applied {
+                Ok(p) if declared.contains(p) || allowed.contains(p) => {}
+                Ok(p) => issues.push(PredicateIssue::Undeclared {
+                    spec: spec.clone(),
+                    predicate: *p,
+                    count: *count,
+                }),
+                Err(name) => issues.push(PredicateIssue::Unknown {
+                    spec: spec.clone(),
+                    name: name.clone(),
+                    count: *count,
+                }),
+            }
+        }
+    }
+    let used = |p: &ElrondKnownPredicate| {
+        specs
+            .iter()
+            .any(|(_, applications)| applications.0.iter().any(|(a, _)| *a == Ok(*p)))
+    };
+    for (i, p) in declared.iter().enumerate() {
+        if !declared[..i].contains(p) && !used(p) {
+            issues.push(PredicateIssue::Unused(*p));
+        }
+    }
+    issues
+}
+
+impl AssertionFile {
+    /// Checks the pre and post specs against `preds`. Predicates in `allowed`, such
+    /// as a setup's, may be applied too.
+    pub fn check_predicates(&self, allowed: &[ElrondKnownPredicate]) -> Vec<PredicateIssue> {
+        let specs = self
+            .pre_spec
+            .iter()
+            .map(|pre| ("pre".to_string(), Applications::of_assertion(&pre.1)))
+            .chain([(
+                "post".to_string(),
+                Applications::of_assertion(&self.post_spec.1),
+            )])
+            .collect();
+        check(&self.preds.0, allowed, specs)
+    }
+}
+
+impl ElrondAssertion {
+    /// Checks the pre and post specs against `preds`. Predicates in `allowed`, such
+    /// as a setup's, may be applied too.
+    pub fn check_predicates(&self, allowed: &[ElrondKnownPredicate]) -> Vec<PredicateIssue> {
+        let specs = self
+            .pre_spec
+            .iter()
+            .map(|pre| ("pre".to_string(), Applications::of_spec(pre)))
+            .chain([("post".to_string(), Applications::of_spec(&self.post_spec))])
+            .collect();
+        check(&self.preds.0, allowed, specs)
+    }
+}
+
+impl ElrondResult {
+    /// Checks every inferred spec against `declared`, usually the setup's predicates.
+    /// Counterexamples carry no specs, so they have no issues.
+    pub fn check_predicates(&self, declared: &ElrondPredicates) -> Vec<PredicateIssue> {
+        match self {
+            ElrondResult::Result(specs) => {
+                let specs = specs
+                    .iter()
+                    .map(|(spec, name)| (name.clone(), Applications::of_spec(spec)))
+                    .collect();
+                check(&declared.0, &[], specs)
+            }
+            ElrondResult::Cex(_) => vec![],
+        }
+    }
+}
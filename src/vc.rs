@@ -0,0 +1,390 @@
+use std::fmt::Display;
+
+use itertools::Itertools;
+use serde::Deserialize;
+
+use crate::ipc::{
+    ElrondForallFormula, ElrondLiteral, ElrondPred, ElrondSimpleExpr, ElrondSpec, ElrondTpedvar,
+    ElrondType, FreeVar,
+};
+
+/// The verification condition Elrond writes to `<outputdir>/_beforeweakening.json`.
+/// See language/specast.ml -> vc
+#[derive(PartialEq, Debug)]
+pub struct Vc {
+    pub preds: Vec<String>,
+    pub multi_pre: Vec<VcPre>,
+    pub post: VcAssertion,
+    pub spectable: Vec<(ElrondSpec, String)>,
+    pub vars: Vec<ElrondTpedvar>,
+    pub inputs: Vec<ElrondTpedvar>,
+    pub outputs: Vec<ElrondTpedvar>,
+}
+
+/// One path through the client, with the library calls made along it.
+#[derive(PartialEq, Debug)]
+pub struct VcPre {
+    pub pre_flow: VcAssertion,
+    pub applied_args: Vec<(String, Vec<Vec<ElrondSimpleExpr>>)>,
+}
+
+#[derive(PartialEq, Debug)]
+pub enum VcAssertion {
+    SpecApply(String, Vec<ElrondSimpleExpr>),
+    Implies(Box<VcAssertion>, Box<VcAssertion>),
+    And(Vec<VcAssertion>),
+    Or(Vec<VcAssertion>),
+    Not(Box<VcAssertion>),
+    Iff(Box<VcAssertion>, Box<VcAssertion>),
+}
+
+impl VcAssertion {
+    /// Names of every spec applied in this assertion, in order of appearance.
+    pub fn applied_specs(&self) -> Vec<&str> {
+        match self {
+            VcAssertion::SpecApply(name, _) => vec![name.as_str()],
+            VcAssertion::Implies(a1, a2) | VcAssertion::Iff(a1, a2) => {
+                let mut names = a1.applied_specs();
+                names.extend(a2.applied_specs());
+                names
+            }
+            VcAssertion::Not(a) => a.applied_specs(),
+            VcAssertion::And(a_vec) | VcAssertion::Or(a_vec) => {
+                a_vec.iter().flat_map(VcAssertion::applied_specs).collect()
+            }
+        }
+    }
+}
+
+impl Display for VcAssertion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VcAssertion::SpecApply(name, args) => {
+                write!(
+                    f,
+                    "{name} {}",
+                    args.iter().map(ToString::to_string).join(" ")
+                )
+            }
+            VcAssertion::Implies(lhs, rhs) => write!(f, "implies ({lhs}) ({rhs})"),
+            VcAssertion::And(a_vec) => write!(
+                f,
+                "({})",
+                a_vec.iter().map(ToString::to_string).join(" && ")
+            ),
+            VcAssertion::Or(a_vec) => write!(
+                f,
+                "({})",
+                a_vec.iter().map(ToString::to_string).join(" || ")
+            ),
+            VcAssertion::Not(a) => write!(f, "(!{a})"),
+            VcAssertion::Iff(lhs, rhs) => write!(f, "iff ({lhs}) ({rhs})"),
+        }
+    }
+}
+
+impl Vc {
+    pub fn from_file(path: &str) -> Result<Vc, String> {
+        let contents = std::fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?;
+        contents.parse()
+    }
+
+    /// The library functions (holes) whose specs the client's VC depends on, in
+    /// order of first appearance.
+    pub fn holes(&self) -> Vec<&str> {
+        self.multi_pre
+            .iter()
+            .flat_map(|pre| pre.applied_args.iter().map(|(name, _)| name.as_str()))
+            .unique()
+            .collect()
+    }
+
+    /// Every spec applied in the VC, holes and builtins (`equal`, `concat_post`, ...) alike.
+    pub fn applied_specs(&self) -> Vec<&str> {
+        self.multi_pre
+            .iter()
+            .flat_map(|pre| pre.pre_flow.applied_specs())
+            .chain(self.post.applied_specs())
+            .unique()
+            .collect()
+    }
+
+    pub fn spec(&self, name: &str) -> Option<&ElrondSpec> {
+        self.spectable
+            .iter()
+            .find_map(|(spec, n)| (n == name).then_some(spec))
+    }
+}
+
+impl std::str::FromStr for Vc {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let file: RawVcFile = serde_json::from_str(s).map_err(|e| e.to_string())?;
+        Ok(file.vc.into())
+    }
+}
+
+impl Display for Vc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let tpedvars = |vars: &[ElrondTpedvar]| vars.iter().map(ToString::to_string).join(", ");
+
+        writeln!(f, "preds: {}", self.preds.iter().join(", "))?;
+        writeln!(f, "inputs: {}", tpedvars(&self.inputs))?;
+        writeln!(f, "outputs: {}", tpedvars(&self.outputs))?;
+        writeln!(f, "vars: {}", tpedvars(&self.vars))?;
+        for (i, pre) in self.multi_pre.iter().enumerate() {
+            writeln!(f, "pre {i}:")?;
+            writeln!(f, "\t{}", pre.pre_flow)?;
+        }
+        writeln!(f, "post:")?;
+        writeln!(f, "\t{}", self.post)?;
+        writeln!(f, "specs:")?;
+        for name in self.applied_specs() {
+            match self.spec(name) {
+                Some(spec) => writeln!(f, "\t{name} : {spec}")?,
+                None => writeln!(f, "\t{name} : ?")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/* The engine dumps its ASTs as `{"t": <kind>, "f": <constructor>, "v": <args>}`
+ * objects, where `v` is always a list, even for constructors with zero or one
+ * argument. The raw types below mirror that layout and are lowered into the
+ * Elrond types above. */
+
+#[derive(Deserialize)]
+struct RawVcFile {
+    vc: RawVc,
+}
+
+#[derive(Deserialize)]
+struct RawVc {
+    preds: Vec<String>,
+    multi_pre: Vec<RawVcPre>,
+    post: RawAssertion,
+    spectable: Vec<RawSpecEntry>,
+    vars: Vec<RawTpedvar>,
+    inputs: Vec<RawTpedvar>,
+    outputs: Vec<RawTpedvar>,
+}
+
+#[derive(Deserialize)]
+struct RawVcPre {
+    pre_flow: RawAssertion,
+    applied_args_map: Vec<RawAppliedArgs>,
+}
+
+#[derive(Deserialize)]
+struct RawAppliedArgs {
+    name: String,
+    applied_args: Vec<Vec<RawSimpleExpr>>,
+}
+
+#[derive(Deserialize)]
+struct RawSpecEntry {
+    name: String,
+    spec: RawSpec,
+}
+
+#[derive(Deserialize)]
+struct RawSpec {
+    args: Vec<RawTpedvar>,
+    specbody: RawForallFormula,
+}
+
+#[derive(Deserialize)]
+struct RawForallFormula {
+    qv: Vec<RawTpedvar>,
+    body: RawPred,
+}
+
+#[derive(Deserialize)]
+struct RawTpedvar {
+    tp: RawType,
+    n: String,
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Deserialize)]
+enum RawType {
+    B,
+    I,
+    IL,
+    IT,
+    ITI,
+    ITB,
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Deserialize)]
+#[serde(tag = "f", content = "v")]
+enum RawAssertion {
+    ASpecApply(String, Vec<RawSimpleExpr>),
+    AImplies(Box<RawAssertion>, Box<RawAssertion>),
+    AAnd(Vec<RawAssertion>),
+    AOr(Vec<RawAssertion>),
+    ANot((Box<RawAssertion>,)),
+    AIff(Box<RawAssertion>, Box<RawAssertion>),
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Deserialize)]
+#[serde(tag = "f", content = "v")]
+enum RawPred {
+    ETrue([(); 0]),
+    EAtom((RawSimpleExpr,)),
+    EImplies(Box<RawPred>, Box<RawPred>),
+    EIte(Box<RawPred>, Box<RawPred>, Box<RawPred>),
+    ENot((Box<RawPred>,)),
+    EAnd(Vec<RawPred>),
+    EOr(Vec<RawPred>),
+    EIff(Box<RawPred>, Box<RawPred>),
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "f", content = "v")]
+enum RawSimpleExpr {
+    Lit(RawType, RawLiteral),
+    V(RawType, String),
+    Op(RawType, String, Vec<RawSimpleExpr>),
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "f", content = "v")]
+enum RawLiteral {
+    Int(i64),
+    Bool(bool),
+    IntList(Vec<i64>),
+}
+
+impl From<RawVc> for Vc {
+    fn from(raw: RawVc) -> Self {
+        let tpedvars = |vars: Vec<RawTpedvar>| vars.into_iter().map(Into::into).collect();
+        Vc {
+            preds: raw.preds,
+            multi_pre: raw.multi_pre.into_iter().map(Into::into).collect(),
+            post: raw.post.into(),
+            spectable: raw
+                .spectable
+                .into_iter()
+                .map(|entry| (entry.spec.into(), entry.name))
+                .collect(),
+            vars: tpedvars(raw.vars),
+            inputs: tpedvars(raw.inputs),
+            outputs: tpedvars(raw.outputs),
+        }
+    }
+}
+
+impl From<RawVcPre> for VcPre {
+    fn from(raw: RawVcPre) -> Self {
+        VcPre {
+            pre_flow: raw.pre_flow.into(),
+            applied_args: raw
+                .applied_args_map
+                .into_iter()
+                .map(|entry| {
+                    let args = entry
+                        .applied_args
+                        .into_iter()
+                        .map(|args| args.into_iter().map(Into::into).collect())
+                        .collect();
+                    (entry.name, args)
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<RawSpec> for ElrondSpec {
+    fn from(raw: RawSpec) -> Self {
+        let RawForallFormula { qv, body } = raw.specbody;
+        ElrondSpec(
+            raw.args.into_iter().map(Into::into).collect(),
+            ElrondForallFormula(
+                qv.into_iter()
+                    .map(|RawTpedvar { tp, n }| FreeVar(tp.into(), n))
+                    .collect(),
+                body.into(),
+            ),
+        )
+    }
+}
+
+impl From<RawTpedvar> for ElrondTpedvar {
+    fn from(raw: RawTpedvar) -> Self {
+        ElrondTpedvar(raw.tp.into(), raw.n)
+    }
+}
+
+impl From<RawType> for ElrondType {
+    fn from(raw: RawType) -> Self {
+        match raw {
+            RawType::B => ElrondType::Bool,
+            RawType::I => ElrondType::Int,
+            RawType::IL => ElrondType::IntList,
+            RawType::IT => ElrondType::IntTree,
+            RawType::ITI => ElrondType::IntTreeI,
+            RawType::ITB => ElrondType::IntTreeB,
+        }
+    }
+}
+
+impl From<RawAssertion> for VcAssertion {
+    fn from(raw: RawAssertion) -> Self {
+        let boxed = |a: Box<RawAssertion>| Box::new(VcAssertion::from(*a));
+        let all = |a_vec: Vec<RawAssertion>| a_vec.into_iter().map(Into::into).collect();
+        match raw {
+            RawAssertion::ASpecApply(name, args) => {
+                VcAssertion::SpecApply(name, args.into_iter().map(Into::into).collect())
+            }
+            RawAssertion::AImplies(a1, a2) => VcAssertion::Implies(boxed(a1), boxed(a2)),
+            RawAssertion::AAnd(a_vec) => VcAssertion::And(all(a_vec)),
+            RawAssertion::AOr(a_vec) => VcAssertion::Or(all(a_vec)),
+            RawAssertion::ANot((a,)) => VcAssertion::Not(boxed(a)),
+            RawAssertion::AIff(a1, a2) => VcAssertion::Iff(boxed(a1), boxed(a2)),
+        }
+    }
+}
+
+impl From<RawPred> for ElrondPred {
+    fn from(raw: RawPred) -> Self {
+        let boxed = |p: Box<RawPred>| Box::new(ElrondPred::from(*p));
+        let all = |p_vec: Vec<RawPred>| p_vec.into_iter().map(Into::into).collect();
+        match raw {
+            RawPred::ETrue(_) => ElrondPred::True,
+            RawPred::EAtom((e,)) => ElrondPred::Atom(e.into()),
+            RawPred::EImplies(p1, p2) => ElrondPred::Implies(boxed(p1), boxed(p2)),
+            RawPred::EIte(p1, p2, p3) => ElrondPred::Ite(boxed(p1), boxed(p2), boxed(p3)),
+            RawPred::ENot((p,)) => ElrondPred::Not(boxed(p)),
+            RawPred::EAnd(p_vec) => ElrondPred::And(all(p_vec)),
+            RawPred::EOr(p_vec) => ElrondPred::Or(all(p_vec)),
+            RawPred::EIff(p1, p2) => ElrondPred::Iff(boxed(p1), boxed(p2)),
+        }
+    }
+}
+
+impl From<RawSimpleExpr> for ElrondSimpleExpr {
+    fn from(raw: RawSimpleExpr) -> Self {
+        match raw {
+            RawSimpleExpr::Lit(tp, lit) => ElrondSimpleExpr::Literal(tp.into(), lit.into()),
+            RawSimpleExpr::V(tp, name) => ElrondSimpleExpr::Var(tp.into(), name),
+            RawSimpleExpr::Op(tp, op, args) => {
+                ElrondSimpleExpr::Op(tp.into(), op, args.into_iter().map(Into::into).collect())
+            }
+        }
+    }
+}
+
+impl From<RawLiteral> for ElrondLiteral {
+    fn from(raw: RawLiteral) -> Self {
+        match raw {
+            RawLiteral::Int(i) => ElrondLiteral::Int(i),
+            RawLiteral::Bool(b) => ElrondLiteral::Bool(b),
+            RawLiteral::IntList(l) => ElrondLiteral::IntList(l),
+        }
+    }
+}
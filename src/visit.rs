@@ -0,0 +1,210 @@
+//! Traversals of `ElrondPred`, `ElrondSimpleExpr` and `Assertion`.
+//!
+//! Each trait method defaults to the matching `walk_*` function, which recurses
+//! into the children. An analysis overrides the cases it cares about and calls
+//! the `walk_*` function to keep descending.
+
+use crate::{
+    ipc::{ElrondPred, ElrondSimpleExpr},
+    ipc_assertion::Assertion,
+};
+
+/// Read-only traversal.
+pub trait Visitor {
+    fn visit_pred(&mut self, p: &ElrondPred) {
+        walk_pred(self, p)
+    }
+
+    fn visit_expr(&mut self, e: &ElrondSimpleExpr) {
+        walk_expr(self, e)
+    }
+
+    fn visit_assertion(&mut self, a: &Assertion) {
+        walk_assertion(self, a)
+    }
+
+    /// A variable of an `Assertion`, which has no expression type of its own.
+    fn visit_name(&mut self, _name: &str) {}
+}
+
+pub fn walk_pred<V: Visitor + ?Sized>(v: &mut V, p: &ElrondPred) {
+    match p {
+        ElrondPred::True => {}
+        ElrondPred::Atom(e) => v.visit_expr(e),
+        ElrondPred::Implies(p1, p2) | ElrondPred::Iff(p1, p2) => {
+            v.visit_pred(p1);
+            v.visit_pred(p2);
+        }
+        ElrondPred::Ite(p1, p2, p3) => {
+            v.visit_pred(p1);
+            v.visit_pred(p2);
+            v.visit_pred(p3);
+        }
+        ElrondPred::Not(p) => v.visit_pred(p),
+        ElrondPred::And(p_vec) | ElrondPred::Or(p_vec) => {
+            p_vec.iter().for_each(|p| v.visit_pred(p))
+        }
+    }
+}
+
+pub fn walk_expr<V: Visitor + ?Sized>(v: &mut V, e: &ElrondSimpleExpr) {
+    match e {
+        ElrondSimpleExpr::Literal(..) | ElrondSimpleExpr::Var(..) => {}
+        ElrondSimpleExpr::Op(_, _, args) | ElrondSimpleExpr::Tuple(args) => {
+            args.iter().for_each(|e| v.visit_expr(e))
+        }
+    }
+}
+
+pub fn walk_assertion<V: Visitor + ?Sized>(v: &mut V, a: &Assertion) {
+    match a {
+        Assertion::True => {}
+        Assertion::Predicate(_, args) => args.iter().for_each(|n| v.visit_name(n)),
+        Assertion::Op(_, v1, v2) => {
+            v.visit_name(v1);
+            v.visit_name(v2);
+        }
+        Assertion::Implies(a1, a2) | Assertion::Iff(a1, a2) => {
+            v.visit_assertion(a1);
+            v.visit_assertion(a2);
+        }
+        Assertion::Not(a) => v.visit_assertion(a),
+        Assertion::And(a_vec) | Assertion::Or(a_vec) => {
+            a_vec.iter().for_each(|a| v.visit_assertion(a))
+        }
+    }
+}
+
+/// In-place traversal.
+pub trait VisitorMut {
+    fn visit_pred_mut(&mut self, p: &mut ElrondPred) {
+        walk_pred_mut(self, p)
+    }
+
+    fn visit_expr_mut(&mut self, e: &mut ElrondSimpleExpr) {
+        walk_expr_mut(self, e)
+    }
+
+    fn visit_assertion_mut(&mut self, a: &mut Assertion) {
+        walk_assertion_mut(self, a)
+    }
+
+    fn visit_name_mut(&mut self, _name: &mut String) {}
+}
+
+pub fn walk_pred_mut<V: VisitorMut + ?Sized>(v: &mut V, p: &mut ElrondPred) {
+    match p {
+        ElrondPred::True => {}
+        ElrondPred::Atom(e) => v.visit_expr_mut(e),
+        ElrondPred::Implies(p1, p2) | ElrondPred::Iff(p1, p2) => {
+            v.visit_pred_mut(p1);
+            v.visit_pred_mut(p2);
+        }
+        ElrondPred::Ite(p1, p2, p3) => {
+            v.visit_pred_mut(p1);
+            v.visit_pred_mut(p2);
+            v.visit_pred_mut(p3);
+        }
+        ElrondPred::Not(p) => v.visit_pred_mut(p),
+        ElrondPred::And(p_vec) | ElrondPred::Or(p_vec) => {
+            p_vec.iter_mut().for_each(|p| v.visit_pred_mut(p))
+        }
+    }
+}
+
+pub fn walk_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, e: &mut ElrondSimpleExpr) {
+    match e {
+        ElrondSimpleExpr::Literal(..) | ElrondSimpleExpr::Var(..) => {}
+        ElrondSimpleExpr::Op(_, _, args) | ElrondSimpleExpr::Tuple(args) => {
+            args.iter_mut().for_each(|e| v.visit_expr_mut(e))
+        }
+    }
+}
+
+pub fn walk_assertion_mut<V: VisitorMut + ?Sized>(v: &mut V, a: &mut Assertion) {
+    match a {
+        Assertion::True => {}
+        Assertion::Predicate(_, args) => args.iter_mut().for_each(|n| v.visit_name_mut(n)),
+        Assertion::Op(_, v1, v2) => {
+            v.visit_name_mut(v1);
+            v.visit_name_mut(v2);
+        }
+        Assertion::Implies(a1, a2) | Assertion::Iff(a1, a2) => {
+            v.visit_assertion_mut(a1);
+            v.visit_assertion_mut(a2);
+        }
+        Assertion::Not(a) => v.visit_assertion_mut(a),
+        Assertion::And(a_vec) | Assertion::Or(a_vec) => {
+            a_vec.iter_mut().for_each(|a| v.visit_assertion_mut(a))
+        }
+    }
+}
+
+/// Rebuilding traversal, for rewrites that change the shape of the tree.
+pub trait Fold {
+    fn fold_pred(&mut self, p: ElrondPred) -> ElrondPred {
+        walk_pred_fold(self, p)
+    }
+
+    fn fold_expr(&mut self, e: ElrondSimpleExpr) -> ElrondSimpleExpr {
+        walk_expr_fold(self, e)
+    }
+
+    fn fold_assertion(&mut self, a: Assertion) -> Assertion {
+        walk_assertion_fold(self, a)
+    }
+
+    fn fold_name(&mut self, name: String) -> String {
+        name
+    }
+}
+
+pub fn walk_pred_fold<F: Fold + ?Sized>(f: &mut F, p: ElrondPred) -> ElrondPred {
+    let mut fold = |p: Box<ElrondPred>| Box::new(f.fold_pred(*p));
+    match p {
+        ElrondPred::True => ElrondPred::True,
+        ElrondPred::Atom(e) => ElrondPred::Atom(f.fold_expr(e)),
+        ElrondPred::Implies(p1, p2) => ElrondPred::Implies(fold(p1), fold(p2)),
+        ElrondPred::Iff(p1, p2) => ElrondPred::Iff(fold(p1), fold(p2)),
+        ElrondPred::Ite(p1, p2, p3) => ElrondPred::Ite(fold(p1), fold(p2), fold(p3)),
+        ElrondPred::Not(p) => ElrondPred::Not(fold(p)),
+        ElrondPred::And(p_vec) => {
+            ElrondPred::And(p_vec.into_iter().map(|p| f.fold_pred(p)).collect())
+        }
+        ElrondPred::Or(p_vec) => {
+            ElrondPred::Or(p_vec.into_iter().map(|p| f.fold_pred(p)).collect())
+        }
+    }
+}
+
+pub fn walk_expr_fold<F: Fold + ?Sized>(f: &mut F, e: ElrondSimpleExpr) -> ElrondSimpleExpr {
+    match e {
+        ElrondSimpleExpr::Literal(..) | ElrondSimpleExpr::Var(..) => e,
+        ElrondSimpleExpr::Op(ty, op, args) => {
+            ElrondSimpleExpr::Op(ty, op, args.into_iter().map(|e| f.fold_expr(e)).collect())
+        }
+        ElrondSimpleExpr::Tuple(args) => {
+            ElrondSimpleExpr::Tuple(args.into_iter().map(|e| f.fold_expr(e)).collect())
+        }
+    }
+}
+
+pub fn walk_assertion_fold<F: Fold + ?Sized>(f: &mut F, a: Assertion) -> Assertion {
+    let mut fold = |a: Box<Assertion>| Box::new(f.fold_assertion(*a));
+    match a {
+        Assertion::True => Assertion::True,
+        Assertion::Predicate(p, args) => {
+            Assertion::Predicate(p, args.into_iter().map(|n| f.fold_name(n)).collect())
+        }
+        Assertion::Op(o, v1, v2) => Assertion::Op(o, f.fold_name(v1), f.fold_name(v2)),
+        Assertion::Implies(a1, a2) => Assertion::Implies(fold(a1), fold(a2)),
+        Assertion::Iff(a1, a2) => Assertion::Iff(fold(a1), fold(a2)),
+        Assertion::Not(a) => Assertion::Not(fold(a)),
+        Assertion::And(a_vec) => {
+            Assertion::And(a_vec.into_iter().map(|a| f.fold_assertion(a)).collect())
+        }
+        Assertion::Or(a_vec) => {
+            Assertion::Or(a_vec.into_iter().map(|a| f.fold_assertion(a)).collect())
+        }
+    }
+}
@@ -3,18 +3,61 @@ module Vc = Language.SpecAst
 open Printf
 open Ipc
 
-(* type infer_action = InferConsistent (*| InferFull | InferWeakening*) *)
-
 (* let dir = "ADT-Lemma-Discovery"
    (* let action = InferConsistent *)
    let sourcefile = String.cat dir "/data/customstk.ml"
    let assertionfile = String.cat dir "/data/customstk_assertion1.ml"
    let outputdir = "customstk_out" *)
 
-let start (* action *) sourcefile assertionfile outputdir client_name preds =
+(* Inline inputs skip Main_lib.parse, which only knows how to open a path *)
+let parse_input = function
+  | File { path } -> Main_lib.parse path
+  | Inline { text } -> Parse.implementation (Lexing.from_string text)
+
+(* An empty hole list means every library function the source declares *)
+let select_holes fnames (selection : elrondHoles option) =
+  match selection with
+  | None -> fnames
+  | Some { holes; trusted } ->
+      let holes = if holes = [] then fnames else holes in
+      List.filter (fun f -> not (List.mem f trusted)) holes
+
+(* The engine samples through the global Random state, so it is seeded before
+   any phase starts *)
+let apply_options (options : inferenceOptions) = Random.init options.seed
+
+exception Phase_timeout of elrondInferMode
+
+(* The engine has no timeout of its own, so each phase runs under an alarm.
+   The handler only fires once control is back in OCaml, so a long solver
+   call overruns the limit until it returns *)
+let with_timeout (options : inferenceOptions) phase f =
+  match options.phase_timeout_secs with
+  | None -> f ()
+  | Some secs ->
+      let previous =
+        Sys.signal Sys.sigalrm
+          (Sys.Signal_handle (fun _ -> raise (Phase_timeout phase)))
+      in
+      let _ = Unix.alarm secs in
+      Fun.protect
+        ~finally:(fun () ->
+          let _ = Unix.alarm 0 in
+          Sys.set_signal Sys.sigalrm previous)
+        f
+
+(* do_consistent writes into outputdir with a leading '_': a run with
+   outputdir = "customstk_out" leaves its specs in _customstk_out/_consistent.json.
+   Keep in sync with ArwenSetup::consistent_specs in src/setup.rs *)
+let consistent_file outputdir =
+  Filename.concat ("_" ^ outputdir) "_consistent.json"
+
+let start mode selection options sourcefile assertionfile outputdir client_name
+    preds =
+  let () = apply_options options in
   let ctx = Main_lib.init () in
-  let source = Main_lib.parse sourcefile in
-  let assertion = Main_lib.parse assertionfile in
+  let source = parse_input sourcefile in
+  let assertion = parse_input assertionfile in
 
   let mii, vc, holes, _preds, spectab =
     let ( _client_name,
@@ -60,7 +103,9 @@ let start (* action *) sourcefile assertionfile outputdir client_name preds =
     (* let _ = printf "body:=\n%s\n" (Vc.layout vc) in *)
     (* let _ = printf "vc:%s\n" (Vc.vc_layout vc); raise @@ InterExn "end" in *)
     let preds = Translate.TenvEngine.all_preds tenv preds in
-    let holes = Translate.make_holes fnames tenv.funcm imp_map in
+    let holes =
+      Translate.make_holes (select_holes fnames selection) tenv.funcm imp_map
+    in
     let uvars = Vc.get_uvars vc in
     (* let _ = printf "%s\n" (List.to_string T.layouttvar uvars); raise @@ InterExn "end" in *)
     (* let _ = printf "%s\n" (List.to_string T.layouttvar uinputs) in
@@ -94,14 +139,6 @@ let start (* action *) sourcefile assertionfile outputdir client_name preds =
 
   (* `spectab` is a list of specs. Each spec is a list of predicates. Each predicate *)
 
-  (* `holes` are a list of library functions and their implementations which is hardcoded for each benchmark *)
-  let _fnames =
-    [
-      ("Customstk.push", "Customstk.is_empty", "Customstk.top", "Customstk.tail");
-    ]
-  in
-  let _imp_map = Translate.Impmap.l_to_map (Translate.find "Customstk") in
-
   (* let tenv = Translate.TenvEngine.renew_raw_funcm init_tenv raw_funcm in
      let () = Translate.TenvEngine.print_tenv tenv in
      let tenv, uinputs, body = Translate.parse_client tenv client in *)
@@ -114,26 +151,53 @@ let start (* action *) sourcefile assertionfile outputdir client_name preds =
   in
 
   (* let preds = Translate.TenvEngine.all_preds tenv preds in *)
-  let r () = SpecAbd.do_consistent outputdir ctx mii vc spectab holes preds 1 in
-  let res = Utils.time r in
-
-  res |> fst |> into_elrondResult |> into_elrondMessage
-  |> elrondMessage_to_yojson |> Yojson.Safe.to_string |> print_endline;
-  ()
+  let consistent () =
+    with_timeout options Consistent (fun () ->
+        SpecAbd.do_consistent outputdir ctx mii vc spectab holes preds
+          options.bound)
+  in
+  (* Weakening starts from the specs the consistent phase saved, not from the
+     VC, so it only needs that file and where to write its own results *)
+  let weakening () =
+    with_timeout options Weakening (fun () ->
+        SpecAbd.do_weakening ctx (consistent_file outputdir) outputdir)
+  in
+  let r () =
+    match mode with
+    | Consistent -> consistent ()
+    | Weakening -> weakening ()
+    | Full -> (
+        match consistent () with
+        | SpecAbd.Cex _ as cex -> cex
+        | SpecAbd.Result _ -> weakening ())
+  in
+  match Utils.time r with
+  | exception Phase_timeout phase ->
+      let secs = Option.get options.phase_timeout_secs in
+      eprintf "%s phase timed out after %d(s)!\n"
+        (elrondInferMode_to_string phase)
+        secs;
+      (Message
+         (sprintf "%s phase timed out after %ds"
+            (elrondInferMode_to_string phase)
+            secs)
+        : elrondMessage)
+      |> elrondMessage_to_yojson |> Yojson.Safe.to_string |> print_endline
+  | res ->
+      let () =
+        match res with
+        | SpecAbd.Cex _, delta_time ->
+            eprintf "%s inference failed with Cex in %f(s)!\n"
+              (elrondInferMode_to_string mode)
+              delta_time
+        | SpecAbd.Result _, delta_time ->
+            eprintf "%s inference Succeeded in %f(s)!\n"
+              (elrondInferMode_to_string mode)
+              delta_time
+      in
 
-(* match res with
-   | SpecAbd.Cex _, delta_time ->
-       eprintf "Failed with Cex in %f(s)!\n" delta_time
-   | SpecAbd.Result spectab, delta_time ->
-       let mode_str =
-         (* match action with
-            (*  | InferFull -> "Full" *)
-            | InferConsistent -> *)
-         "Consistent"
-         (* | InferWeakening -> "Weakening" *)
-       in
-       let () = Main_lib.Ast.eprint_spectable spectab in
-       eprintf "%s inference Succeeded in %f(s)!\n" mode_str delta_time *)
+      res |> fst |> into_elrondResult |> into_elrondMessage mode
+      |> elrondMessage_to_yojson |> Yojson.Safe.to_string |> print_endline
 
 (* Take an elrond thing, conver it to yojson, print it out and then wrap it in a message over the wire *)
 let test_helper elrondThing thing_to_yojson =
@@ -158,11 +222,22 @@ let test_loop () =
       let test_message : Ipc.arwenMessage =
         Setup
           {
-            sourcefile = "ADT-Lemma-Discovery/data/customstk.ml";
-            assertionfile = "ADT-Lemma-Discovery/data/customstk_assertion1.ml";
+            sourcefile = File { path = "ADT-Lemma-Discovery/data/customstk.ml" };
+            assertionfile =
+              File { path = "ADT-Lemma-Discovery/data/customstk_assertion1.ml" };
             outputdir = "customstk_out";
             client_name = "concat";
             predicates = [ "list_member"; "list_order" ];
+            mode = Consistent;
+            holes = None;
+            options =
+              {
+                bound = 1;
+                max_quantified_vars = None;
+                sample_budget = None;
+                phase_timeout_secs = None;
+                seed = 0;
+              };
           }
       in
       let test_message_json = arwenMessage_to_yojson test_message in
@@ -364,6 +439,11 @@ let test_loop () =
             Result [ (([ (Int, "x") ], ([], True)), "x") ]
           in
           test_helper x elrondResult_to_yojson
+      | Ok (Message "Mode Full") ->
+          test_helper (Full : elrondInferMode) elrondInferMode_to_yojson
+      | Ok (Message "Result Consistent (Spec [])") ->
+          Result (Consistent, Result [])
+          |> elrondMessage_to_yojson |> Yojson.Safe.to_string |> print_endline
       | Ok (Message ("[\"list_member\",\"list_order\"]" as s)) ->
           let x : elrondPredicates = [ "list_member"; "list_order" ] in
           assert (
@@ -372,12 +452,26 @@ let test_loop () =
           test_helper x elrondPredicates_to_yojson
       | Ok
           (Setup
-            { sourcefile; assertionfile; outputdir; client_name; predicates })
-        ->
+            {
+              sourcefile;
+              assertionfile;
+              outputdir;
+              client_name;
+              predicates;
+              mode;
+              holes = _;
+              options = _;
+            }) ->
           test_message_helper
             (String.concat " "
                (List.append
-                  [ sourcefile; assertionfile; outputdir; client_name ]
+                  [
+                    arwenInput_to_string sourcefile;
+                    arwenInput_to_string assertionfile;
+                    outputdir;
+                    client_name;
+                    elrondInferMode_to_string mode;
+                  ]
                   predicates))
       | Ok _ ->
           eprintf "Erroring out with unplanned case";
@@ -395,9 +489,20 @@ let start_loop () =
   let message_json = Yojson.Safe.from_string message_str in
   let message = arwenMessage_of_yojson message_json in
   match message with
-  | Ok (Setup { sourcefile; assertionfile; outputdir; client_name; predicates })
-    ->
-      start sourcefile assertionfile outputdir client_name predicates
+  | Ok
+      (Setup
+        {
+          sourcefile;
+          assertionfile;
+          outputdir;
+          client_name;
+          predicates;
+          mode;
+          holes;
+          options;
+        }) ->
+      start mode holes options sourcefile assertionfile outputdir client_name
+        predicates
   | Ok _ ->
       eprintf "Erroring out with unplanned case : %s" message_str;
       exit 1
@@ -6,7 +6,7 @@ macro_rules! make_test {
             let parser = arwen_elrond_ipc::assertion_parser::$parser::new();
             let pred = $str.to_string();
             let parsed_pred = parser.parse(&pred).unwrap();
-            insta::assert_display_snapshot!(parsed_pred);
+            insta::assert_snapshot!(parsed_pred);
         }
     };
 }
@@ -52,7 +52,7 @@ macro_rules! make_assertion_test {
             let assertionfile = $assertion_file;
             let assertion = std::fs::read_to_string(assertionfile).unwrap();
 
-            insta::assert_display_snapshot!(parser.parse(&assertion).unwrap());
+            insta::assert_snapshot!(parser.parse(&assertion).unwrap());
         }
     };
 }
@@ -0,0 +1,79 @@
+mod common;
+
+use arwen_elrond_ipc::{
+    ipc::{
+        ArwenInput, ElrondAssertion, ElrondForallFormula, ElrondPred, ElrondSimpleExpr, ElrondSpec,
+        ElrondTpedvar, ElrondType, FreeVar,
+    },
+    ipc_assertion::AssertionFile,
+};
+use common::{assertion, parse};
+
+const ASSERTION: &str = "let preds = [| \"mem\"; \"hd\" |]
+
+let pre (l1 : Customstk.t) (u : int) =
+  mem l1 u
+
+let post (l1 : Customstk.t) (l2 : Customstk.t) (u : int) (v : int) =
+  implies (hd l1 u) (mem l2 v || u == v) && u <= v
+";
+
+#[test]
+fn test_assertion_file_serde_round_trip() {
+    let file = parse(ASSERTION);
+    let json = serde_json::to_string(&file).unwrap();
+    let back: AssertionFile = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, file);
+    assert_eq!(back.to_string(), file.to_string());
+}
+
+#[test]
+fn test_elrond_assertion_serde_round_trip() {
+    let assertion = assertion(ASSERTION);
+    let json = serde_json::to_string(&assertion).unwrap();
+    let back: ElrondAssertion = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, assertion);
+    assert_eq!(back.to_string(), assertion.to_string());
+}
+
+#[test]
+fn test_written_files_parse_back() {
+    let file = parse(ASSERTION);
+    assert_eq!(parse(&file.to_string()), file);
+
+    let assertion = ElrondAssertion::try_from(&file).unwrap();
+    let written = parse(&assertion.to_string());
+    assert_eq!(ElrondAssertion::try_from(&written).unwrap(), assertion);
+}
+
+#[test]
+fn test_free_vars_written_as_arguments() {
+    let mut assertion = assertion(ASSERTION);
+    let stk = ElrondType::IntList;
+    assertion.post_spec = ElrondSpec(
+        vec![ElrondTpedvar(stk.clone(), "l".to_string())],
+        ElrondForallFormula(
+            vec![FreeVar(ElrondType::Int, "u".to_string())],
+            ElrondPred::Atom(ElrondSimpleExpr::Op(
+                ElrondType::Bool,
+                "mem".to_string(),
+                vec![
+                    ElrondSimpleExpr::Var(stk, "l".to_string()),
+                    ElrondSimpleExpr::Var(ElrondType::Int, "u".to_string()),
+                ],
+            )),
+        ),
+    );
+    let written = assertion.to_string();
+    assert!(written.contains("let post (l : Customstk.t) (u : int) =\n  mem l u\n"));
+    parse(&written);
+}
+
+#[test]
+fn test_assertion_as_setup_input() {
+    let assertion = assertion(ASSERTION);
+    let input = ArwenInput::from(&assertion);
+    let text = input.read().unwrap();
+    let lowered = ElrondAssertion::try_from(&parse(&text)).unwrap();
+    assert_eq!(lowered, assertion);
+}
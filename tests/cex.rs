@@ -0,0 +1,85 @@
+mod common;
+
+use arwen_elrond_ipc::ipc::{Counterexample, ElrondResult, ElrondTpedvar, ElrondType, ElrondValue};
+use common::vc;
+
+fn cex() -> Counterexample {
+    Counterexample::new(vec![
+        (ElrondValue::L(vec![1, 2]), "s2".to_string()),
+        (ElrondValue::L(vec![3]), "il_0".to_string()),
+        (ElrondValue::I(0), "u".to_string()),
+        (ElrondValue::L(vec![]), "s1".to_string()),
+    ])
+}
+
+#[test]
+fn test_cex_lookup_and_order() {
+    let cex = cex();
+    assert_eq!(cex.get("s2"), Some(&ElrondValue::L(vec![1, 2])));
+    assert_eq!(cex.get("u"), Some(&ElrondValue::I(0)));
+    assert_eq!(cex.get("v"), None);
+    assert_eq!(
+        cex.names().collect::<Vec<_>>(),
+        vec!["il_0", "s1", "s2", "u"]
+    );
+}
+
+#[test]
+fn test_cex_wire_format() {
+    let json = r#"{"Cex":[[[{"I":0},"u"],[{"L":[1,2]},"s2"]]]}"#;
+    let result: ElrondResult = serde_json::from_str(json).unwrap();
+    let ElrondResult::Cex(cexs) = &result else {
+        panic!("expected a Cex, got {result:?}")
+    };
+    assert_eq!(cexs[0].get("s2"), Some(&ElrondValue::L(vec![1, 2])));
+    assert_eq!(
+        serde_json::to_string(&result).unwrap(),
+        r#"{"Cex":[[[{"L":[1,2]},"s2"],[{"I":0},"u"]]]}"#
+    );
+}
+
+#[test]
+fn test_cex_group() {
+    let cex = cex();
+    let int_list = |n: &str| ElrondTpedvar(ElrondType::IntList, n.to_string());
+    let grouped = cex.group(&[int_list("s1"), int_list("s2")], &[int_list("il_0")]);
+    assert_eq!(
+        grouped.inputs,
+        vec![
+            ("s1", &ElrondValue::L(vec![])),
+            ("s2", &ElrondValue::L(vec![1, 2]))
+        ]
+    );
+    assert_eq!(grouped.outputs, vec![("il_0", &ElrondValue::L(vec![3]))]);
+    assert_eq!(grouped.others, vec![("u", &ElrondValue::I(0))]);
+}
+
+#[test]
+fn test_cex_group_by_vc() {
+    let vc = vc();
+    let cex = cex();
+    let grouped = cex.group_by_vc(&vc);
+    assert_eq!(grouped.inputs.len(), 2);
+    assert_eq!(grouped.outputs.len(), 1);
+}
+
+#[test]
+fn test_cex_json() {
+    let cex = cex();
+    insta::assert_snapshot!(serde_json::to_string_pretty(&cex.to_json()).unwrap());
+}
+
+#[test]
+fn test_cex_table() {
+    let cex = cex();
+    insta::assert_snapshot!(cex.table());
+}
+
+#[test]
+fn test_grouped_cex_renderings() {
+    let cex = cex();
+    let int_list = |n: &str| ElrondTpedvar(ElrondType::IntList, n.to_string());
+    let grouped = cex.group(&[int_list("s1"), int_list("s2")], &[int_list("il_0")]);
+    insta::assert_snapshot!(grouped.table());
+    insta::assert_snapshot!(serde_json::to_string(&grouped.to_json()).unwrap());
+}
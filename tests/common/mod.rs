@@ -0,0 +1,41 @@
+//! Fixtures shared by the integration tests. Each test crate uses only some of
+//! them.
+#![allow(dead_code)]
+
+use arwen_elrond_ipc::{
+    assertion_parser::AssertionFileParser, ipc::ElrondAssertion, ipc_assertion::AssertionFile,
+    vc::Vc,
+};
+
+/// The VC the engine wrote for the customstk client `concat`.
+pub const BEFORE_WEAKENING: &str = "_customstk_out/_beforeweakening.json";
+
+/// The post spec of `concat`.
+pub const ASSERTION: &str = "let preds = [| \"mem\"; \"hd\" |]
+
+let post (l1 : Customstk.t) (l2 : Customstk.t) (l3 : Customstk.t) (u : int) =
+  iff (mem l3 u) (mem l1 u || mem l2 u) && implies (hd l3 u) (hd l1 u || hd l2 u)
+";
+
+/// The post spec of `concat`, with a pre spec.
+pub const ASSERTION_WITH_PRE: &str = "let preds = [| \"mem\"; \"hd\"; \"once\" |]
+
+let pre (l1 : Customstk.t) (u : int) =
+  once l1 u
+
+let post (l1 : Customstk.t) (l2 : Customstk.t) (l3 : Customstk.t) (u : int) =
+  iff (mem l3 u) (mem l1 u || mem l2 u) && implies (hd l3 u) (hd l1 u || hd l2 u)
+";
+
+pub fn vc() -> Vc {
+    Vc::from_file(BEFORE_WEAKENING).unwrap()
+}
+
+pub fn parse(text: &str) -> AssertionFile {
+    AssertionFileParser::new().parse(text).unwrap()
+}
+
+/// Parses and lowers an assertion file.
+pub fn assertion(text: &str) -> ElrondAssertion {
+    ElrondAssertion::try_from(&parse(text)).unwrap()
+}
@@ -0,0 +1,165 @@
+mod common;
+
+use arwen_elrond_ipc::{
+    diff::{Connective, DiffStyle, PredDiff, SpecDiff},
+    ipc::{
+        ElrondForallFormula, ElrondPred, ElrondResult, ElrondSimpleExpr, ElrondSpec, ElrondTpedvar,
+        ElrondType,
+    },
+};
+use common::vc;
+
+fn specs() -> Vec<(ElrondSpec, String)> {
+    vc().spectable
+}
+
+fn body_mut<'a>(specs: &'a mut [(ElrondSpec, String)], name: &str) -> &'a mut ElrondPred {
+    let (spec, _) = specs.iter_mut().find(|(_, n)| n == name).unwrap();
+    &mut spec.1 .1
+}
+
+fn app(op: &str, args: &[(ElrondType, &str)]) -> ElrondPred {
+    let args = args
+        .iter()
+        .map(|(ty, n)| ElrondSimpleExpr::Var(ty.clone(), n.to_string()))
+        .collect();
+    ElrondPred::Atom(ElrondSimpleExpr::Op(ElrondType::Bool, op.to_string(), args))
+}
+
+/// The specs with `concat_post`'s conjuncts reversed, an atom of `Customstk.top`
+/// changed and a conjunct added to it, `equal` removed and `Customstk.pop` added.
+fn changed_specs() -> Vec<(ElrondSpec, String)> {
+    let mut specs = specs();
+    let ElrondPred::And(conjuncts) = body_mut(&mut specs, "concat_post") else {
+        panic!("concat_post is a conjunction")
+    };
+    conjuncts.reverse();
+
+    let ElrondPred::Ite(_, then, els) = body_mut(&mut specs, "Customstk.top") else {
+        panic!("Customstk.top is an if")
+    };
+    let ElrondPred::And(conjuncts) = &mut **then else {
+        panic!("its then branch is a conjunction")
+    };
+    conjuncts.reverse();
+    conjuncts.push(app(
+        "list_once",
+        &[(ElrondType::IntList, "il_0"), (ElrondType::Int, "i_0")],
+    ));
+    **els = ElrondPred::Not(Box::new(app(
+        "list_member",
+        &[(ElrondType::IntList, "il_0"), (ElrondType::Int, "u_0")],
+    )));
+
+    specs.retain(|(_, name)| name != "equal");
+    specs.push((
+        ElrondSpec(
+            vec![
+                ElrondTpedvar(ElrondType::IntList, "il_0".to_string()),
+                ElrondTpedvar(ElrondType::Int, "i_0".to_string()),
+            ],
+            ElrondForallFormula(
+                vec![],
+                app(
+                    "list_head",
+                    &[(ElrondType::IntList, "il_0"), (ElrondType::Int, "i_0")],
+                ),
+            ),
+        ),
+        "Customstk.pop".to_string(),
+    ));
+    specs
+}
+
+#[test]
+fn test_diff_identical() {
+    let result = ElrondResult::Result(specs());
+    let diff = result.diff(&result);
+    assert!(diff.is_empty());
+    assert_eq!(diff.to_string(), "");
+    assert_eq!(diff.0.len(), specs().len());
+}
+
+#[test]
+fn test_diff_junctions_order_insensitive() {
+    let a = app(
+        "list_member",
+        &[(ElrondType::IntList, "l"), (ElrondType::Int, "u")],
+    );
+    let b = app(
+        "list_head",
+        &[(ElrondType::IntList, "l"), (ElrondType::Int, "u")],
+    );
+    let c = app(
+        "list_head",
+        &[(ElrondType::IntList, "l"), (ElrondType::Int, "v")],
+    );
+    let old = ElrondPred::Or(vec![a.clone(), b.clone()]);
+    assert!(old
+        .diff(&ElrondPred::Or(vec![b.clone(), a.clone()]))
+        .is_same());
+
+    // `hd l u` is paired with the similar `hd l v` rather than reported as removed
+    let new = ElrondPred::Or(vec![c.clone(), a.clone()]);
+    assert_eq!(
+        old.diff(&new),
+        PredDiff::Node(
+            Connective::Or,
+            vec![PredDiff::Same(a.clone()), PredDiff::Changed(b.clone(), c)]
+        )
+    );
+    assert_eq!(
+        old.diff(&ElrondPred::And(vec![a.clone(), b.clone()])),
+        PredDiff::Changed(old.clone(), ElrondPred::And(vec![a, b]))
+    );
+}
+
+#[test]
+fn test_diff_results() {
+    let diff = ElrondResult::Result(specs()).diff(&ElrondResult::Result(changed_specs()));
+    let changes = diff
+        .changes()
+        .map(|f| {
+            let kind = match f.diff {
+                SpecDiff::Unchanged(_) => "unchanged",
+                SpecDiff::Added(_) => "added",
+                SpecDiff::Removed(_) => "removed",
+                SpecDiff::Changed { .. } => "changed",
+            };
+            format!("{kind} {}", f.name)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        changes,
+        [
+            "removed equal",
+            "changed Customstk.top",
+            "added Customstk.pop"
+        ]
+    );
+    insta::assert_snapshot!(diff.to_string());
+}
+
+#[test]
+fn test_diff_color() {
+    let diff = ElrondResult::Result(specs()).diff(&ElrondResult::Result(changed_specs()));
+    let colored = diff.render(DiffStyle::Color);
+    assert!(colored.contains("\x1b[31m-equal : "));
+    assert!(colored.contains("\x1b[32m+Customstk.pop : "));
+    assert!(colored.contains("\x1b[33m-"));
+    assert!(colored.contains("\x1b[36m@@ Customstk.top @@\x1b[0m"));
+    // Without the escapes it is the unified text
+    let plain = colored
+        .replace("\x1b[0m", "")
+        .replace("\x1b[31m", "")
+        .replace("\x1b[32m", "")
+        .replace("\x1b[33m", "")
+        .replace("\x1b[36m", "");
+    assert_eq!(plain, diff.render(DiffStyle::Unified));
+}
+
+#[test]
+fn test_diff_counterexamples_have_no_specs() {
+    let diff = ElrondResult::Cex(vec![]).diff(&ElrondResult::Result(specs()));
+    assert!(diff.0.iter().all(|f| matches!(f.diff, SpecDiff::Added(_))));
+}
@@ -0,0 +1,160 @@
+mod common;
+
+use arwen_elrond_ipc::{
+    entail::{Bound, EntailError, Relation},
+    ipc::{
+        ElrondForallFormula, ElrondPred, ElrondSimpleExpr, ElrondSpec, ElrondTpedvar, ElrondType,
+        FreeVar,
+    },
+    simplify::NormalForm,
+};
+use common::vc;
+
+fn push() -> ElrondSpec {
+    let vc = vc();
+    vc.spec("Customstk.push").unwrap().clone()
+}
+
+fn var(ty: ElrondType, name: &str) -> ElrondSimpleExpr {
+    ElrondSimpleExpr::Var(ty, name.to_string())
+}
+
+fn app(op: &str, args: Vec<ElrondSimpleExpr>) -> ElrondPred {
+    ElrondPred::Atom(ElrondSimpleExpr::Op(ElrondType::Bool, op.to_string(), args))
+}
+
+fn args(names: [&str; 3]) -> Vec<ElrondTpedvar> {
+    vec![
+        ElrondTpedvar(ElrondType::Int, names[0].to_string()),
+        ElrondTpedvar(ElrondType::IntList, names[1].to_string()),
+        ElrondTpedvar(ElrondType::IntList, names[2].to_string()),
+    ]
+}
+
+/// `x l l' ⊢ mem l' x`
+fn pushed_is_member() -> ElrondSpec {
+    ElrondSpec(
+        args(["x", "l", "l'"]),
+        ElrondForallFormula(
+            vec![],
+            app(
+                "list_member",
+                vec![var(ElrondType::IntList, "l'"), var(ElrondType::Int, "x")],
+            ),
+        ),
+    )
+}
+
+#[test]
+fn test_equivalent_normal_forms() {
+    let push = push();
+    for form in [NormalForm::Simplified, NormalForm::Cnf, NormalForm::Dnf] {
+        let comparison = push
+            .compare(&push.normalize(form), &Bound::default())
+            .unwrap();
+        assert!(comparison.equivalent(), "{form}: {comparison}");
+    }
+    assert!(push.equivalent(&push, &Bound::default()).unwrap());
+}
+
+#[test]
+fn test_stronger_with_witness() {
+    let push = push();
+    let weaker = pushed_is_member();
+    let comparison = push.compare(&weaker, &Bound::default()).unwrap();
+    assert_eq!(comparison.relation(), Relation::Stronger);
+    assert!(comparison.left_entails_right());
+    assert!(!comparison.right_entails_left());
+    insta::assert_snapshot!(comparison.to_string(), @r"
+    A ⊨ B, B ⊭ A on 4800 inputs
+    B but not A:
+    	i_0 -> 0
+    	il_0 -> []
+    	il_1 -> [0, 1]
+    ");
+
+    // The witness is named after A's arguments, and separates the specs
+    let witness = comparison.only_right.unwrap();
+    assert!(push.explain(&witness).unwrap().is_some());
+    let reversed = weaker.compare(&push, &Bound::default()).unwrap();
+    assert_eq!(reversed.relation(), Relation::Weaker);
+    assert!(weaker.entails(&push, &Bound::default()).unwrap().is_some());
+    assert_eq!(push.entails(&weaker, &Bound::default()).unwrap(), None);
+}
+
+#[test]
+fn test_incomparable() {
+    let push = push();
+    // `x l l' ⊢ hd l x`: about the old list instead of the new one
+    let other = ElrondSpec(
+        args(["x", "l", "l'"]),
+        ElrondForallFormula(
+            vec![],
+            app(
+                "list_head",
+                vec![var(ElrondType::IntList, "l"), var(ElrondType::Int, "x")],
+            ),
+        ),
+    );
+    let comparison = push.compare(&other, &Bound::default()).unwrap();
+    assert_eq!(comparison.relation(), Relation::Incomparable);
+    assert!(comparison.only_left.is_some() && comparison.only_right.is_some());
+}
+
+#[test]
+fn test_arguments_aligned_by_position() {
+    // Named so that aligning with push's `i_0` must first move the quantified `i_0`
+    // `u_0 a b ⊢ ∀ i_0. mem b u_0 && (mem a i_0 => mem b i_0)`
+    let int = |n| var(ElrondType::Int, n);
+    let list = |n| var(ElrondType::IntList, n);
+    let other = ElrondSpec(
+        args(["u_0", "a", "b"]),
+        ElrondForallFormula(
+            vec![FreeVar(ElrondType::Int, "i_0".to_string())],
+            ElrondPred::And(vec![
+                app("list_member", vec![list("b"), int("u_0")]),
+                ElrondPred::Implies(
+                    Box::new(app("list_member", vec![list("a"), int("i_0")])),
+                    Box::new(app("list_member", vec![list("b"), int("i_0")])),
+                ),
+            ]),
+        ),
+    );
+    let comparison = push().compare(&other, &Bound::default()).unwrap();
+    assert_eq!(comparison.relation(), Relation::Stronger, "{comparison}");
+}
+
+#[test]
+fn test_bound() {
+    let small = Bound {
+        max_size: 1,
+        min_int: 0,
+        max_int: 1,
+        ..Bound::default()
+    };
+    let comparison = push().compare(&pushed_is_member(), &small).unwrap();
+    // 2 ints and 3 lists each for the two lists
+    assert_eq!(comparison.inputs, 2 * 3 * 3);
+}
+
+#[test]
+fn test_mismatched_specs() {
+    let push = push();
+    let mut fewer = pushed_is_member();
+    fewer.0.pop();
+    assert_eq!(
+        push.compare(&fewer, &Bound::default()),
+        Err(EntailError::Arity { left: 3, right: 2 })
+    );
+
+    let mut retyped = pushed_is_member();
+    retyped.0[0].0 = ElrondType::Bool;
+    assert_eq!(
+        push.compare(&retyped, &Bound::default()),
+        Err(EntailError::ArgType {
+            position: 0,
+            left: ElrondType::Int,
+            right: ElrondType::Bool,
+        })
+    );
+}
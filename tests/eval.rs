@@ -0,0 +1,168 @@
+use arwen_elrond_ipc::{
+    eval::{Env, EvalError},
+    ipc::{
+        Counterexample, ElrondForallFormula, ElrondKnownPredicate, ElrondPred, ElrondSimpleExpr,
+        ElrondType, ElrondValue, FreeVar,
+    },
+};
+
+fn var(ty: ElrondType, name: &str) -> ElrondSimpleExpr {
+    ElrondSimpleExpr::Var(ty, name.to_string())
+}
+
+fn app(op: &str, args: Vec<ElrondSimpleExpr>) -> ElrondPred {
+    ElrondPred::Atom(ElrondSimpleExpr::Op(ElrondType::Bool, op.to_string(), args))
+}
+
+fn env(bindings: &[(&str, ElrondValue)]) -> Env {
+    bindings
+        .iter()
+        .map(|(n, v)| (n.to_string(), v.clone()))
+        .collect()
+}
+
+#[test]
+fn test_list_predicates() {
+    use ElrondKnownPredicate::*;
+    use ElrondValue::{I, L};
+
+    let cases = [
+        (member, vec![L(vec![1, 2]), I(2)], true),
+        (member, vec![L(vec![]), I(2)], false),
+        (head, vec![L(vec![1, 2]), I(1)], true),
+        (head, vec![L(vec![1, 2]), I(2)], false),
+        (head, vec![L(vec![]), I(0)], false),
+        (length, vec![L(vec![1, 2]), I(2)], true),
+        (length, vec![L(vec![]), I(1)], false),
+        (order, vec![L(vec![1, 2, 1]), I(2), I(1)], true),
+        (order, vec![L(vec![1, 2]), I(2), I(1)], false),
+        (order, vec![L(vec![1]), I(1), I(1)], false),
+        (once, vec![L(vec![1, 2]), I(1)], true),
+        (once, vec![L(vec![1, 2, 1]), I(1)], false),
+        (sorted, vec![L(vec![1, 1, 3])], true),
+        (sorted, vec![L(vec![2, 1])], false),
+    ];
+    for (p, args, expected) in cases {
+        assert_eq!(p.apply(&args), Ok(expected), "{p} {args:?}");
+    }
+}
+
+#[test]
+fn test_predicate_names() {
+    let env = env(&[("l", ElrondValue::L(vec![3])), ("u", ElrondValue::I(3))]);
+    let args = || vec![var(ElrondType::IntList, "l"), var(ElrondType::Int, "u")];
+    assert_eq!(app("mem", args()).eval(&env), Ok(true));
+    assert_eq!(app("list_member", args()).eval(&env), Ok(true));
+    assert_eq!(
+        app("succ", args()).eval(&env),
+        Err(EvalError::UnknownOp("succ".to_string()))
+    );
+}
+
+#[test]
+fn test_comparisons_and_connectives() {
+    let env = env(&[("x", ElrondValue::I(1)), ("y", ElrondValue::I(2))]);
+    let x = || var(ElrondType::Int, "x");
+    let y = || var(ElrondType::Int, "y");
+    let le = app("<=", vec![x(), y()]);
+    let ge = app(">=", vec![x(), y()]);
+    let eq = app("==", vec![x(), y()]);
+    assert_eq!(le.eval(&env), Ok(true));
+    assert_eq!(ge.eval(&env), Ok(false));
+    assert_eq!(eq.eval(&env), Ok(false));
+    assert_eq!(
+        ElrondPred::Iff(Box::new(ge.clone()), Box::new(eq.clone())).eval(&env),
+        Ok(true)
+    );
+    assert_eq!(
+        ElrondPred::Ite(Box::new(le), Box::new(ge), Box::new(ElrondPred::True)).eval(&env),
+        Ok(false)
+    );
+    assert_eq!(
+        app("mem", vec![x(), y()]).eval(&env),
+        Err(EvalError::BadArguments {
+            op: "mem".to_string(),
+            args: vec!["1".to_string(), "2".to_string()]
+        })
+    );
+    assert_eq!(
+        app("<=", vec![x(), var(ElrondType::Int, "z")]).eval(&env),
+        Err(EvalError::UnboundVariable("z".to_string()))
+    );
+}
+
+#[test]
+fn test_forall_enumerates_env_values() {
+    // forall u. mem l2 u => mem l1 u
+    let subset = |l1: &str, l2: &str| {
+        ElrondForallFormula(
+            vec![FreeVar(ElrondType::Int, "u".to_string())],
+            ElrondPred::Implies(
+                Box::new(app(
+                    "mem",
+                    vec![var(ElrondType::IntList, l2), var(ElrondType::Int, "u")],
+                )),
+                Box::new(app(
+                    "mem",
+                    vec![var(ElrondType::IntList, l1), var(ElrondType::Int, "u")],
+                )),
+            ),
+        )
+    };
+    let cex = Counterexample::new(vec![
+        (ElrondValue::L(vec![1, 2, 3]), "l1".to_string()),
+        (ElrondValue::L(vec![2, 4]), "l2".to_string()),
+    ]);
+    let env = Env::from(&cex);
+
+    assert_eq!(subset("l2", "l2").eval(&env), Ok(true));
+    assert_eq!(subset("l1", "l2").eval(&env), Ok(false));
+    assert_eq!(
+        subset("l1", "l2").find_violation(&env),
+        Ok(Some(vec![(
+            FreeVar(ElrondType::Int, "u".to_string()),
+            ElrondValue::I(4)
+        )]))
+    );
+}
+
+#[test]
+fn test_domain() {
+    let env = env(&[
+        ("l", ElrondValue::L(vec![3, 1])),
+        ("x", ElrondValue::I(1)),
+        ("b", ElrondValue::B(true)),
+    ]);
+    assert_eq!(
+        env.domain(&ElrondType::Int),
+        vec![ElrondValue::I(1), ElrondValue::I(3)]
+    );
+    assert_eq!(
+        env.domain(&ElrondType::IntList),
+        vec![ElrondValue::L(vec![3, 1])]
+    );
+}
+
+#[test]
+fn test_assignments() {
+    let env = env(&[("l", ElrondValue::L(vec![2, 1]))]);
+    let vars = [
+        FreeVar(ElrondType::Int, "u".to_string()),
+        FreeVar(ElrondType::Bool, "b".to_string()),
+    ];
+    let assignments = env.assignments(&vars).collect::<Vec<_>>();
+    assert_eq!(assignments.len(), 4);
+    let (extended, assignment) = &assignments[1];
+    assert_eq!(
+        assignment,
+        &vec![
+            (vars[0].clone(), ElrondValue::I(1)),
+            (vars[1].clone(), ElrondValue::B(true)),
+        ]
+    );
+    assert_eq!(extended.get("u"), Some(&ElrondValue::I(1)));
+    assert_eq!(extended.get("l"), env.get("l"));
+
+    let none = env.assignments(&[]).collect::<Vec<_>>();
+    assert_eq!(none, vec![(env.clone(), vec![])]);
+}
@@ -0,0 +1,123 @@
+mod common;
+
+use arwen_elrond_ipc::{
+    eval::EvalError,
+    explain::Bindings,
+    ipc::{
+        Counterexample, ElrondAssertion, ElrondPred, ElrondResult, ElrondType, ElrondValue, FreeVar,
+    },
+};
+use common::{assertion, vc, ASSERTION};
+
+/// `l1 l2 l3` bound to the client's `s1 s2 il_0` through `concat_post s1 s2 il_0`.
+fn bindings(assertion: &ElrondAssertion) -> Bindings {
+    Bindings::post_call(&assertion.post_spec.0, &vc()).unwrap()
+}
+
+/// A counterexample as the engine reports it, over the client's variables.
+fn cex(s1: &[i64], s2: &[i64], il_0: &[i64]) -> Counterexample {
+    Counterexample::new(vec![
+        (ElrondValue::L(s1.to_vec()), "s1".to_string()),
+        (ElrondValue::L(s2.to_vec()), "s2".to_string()),
+        (ElrondValue::I(7), "i_0".to_string()),
+        (ElrondValue::L(il_0.to_vec()), "il_0".to_string()),
+    ])
+}
+
+#[test]
+fn test_bindings_from_vc() {
+    let assertion = assertion(ASSERTION);
+    let Bindings(bindings) = bindings(&assertion);
+    let bound = bindings
+        .iter()
+        .map(|(arg, actual)| format!("{arg} = {actual}"))
+        .collect::<Vec<_>>();
+    // `u` is not an argument of the call, so it stays quantified
+    assert_eq!(bound, ["l1 = s1", "l2 = s2", "l3 = il_0"]);
+}
+
+#[test]
+fn test_explain_membership() {
+    let assertion = assertion(ASSERTION);
+    let explanation = assertion
+        .post_spec
+        .explain_with(&cex(&[1], &[], &[2]), &bindings(&assertion))
+        .unwrap()
+        .unwrap();
+    // The unrelated `i_0 -> 7` is not a candidate for `u`
+    assert_eq!(
+        explanation.quantified,
+        vec![(FreeVar(ElrondType::Int, "u".to_string()), ElrondValue::I(1))]
+    );
+    assert!(matches!(explanation.failing(), ElrondPred::Iff(..)));
+    insta::assert_snapshot!(explanation);
+}
+
+#[test]
+fn test_explain_head() {
+    let assertion = assertion(ASSERTION);
+    let explanation = assertion
+        .post_spec
+        .explain_with(&cex(&[1, 2], &[], &[2, 1]), &bindings(&assertion))
+        .unwrap()
+        .unwrap();
+    assert!(matches!(explanation.failing(), ElrondPred::Or(..)));
+    insta::assert_snapshot!(explanation);
+}
+
+#[test]
+fn test_explain_holds() {
+    let assertion = assertion(ASSERTION);
+    let result = ElrondResult::Cex(vec![cex(&[1], &[2], &[1, 2]), cex(&[1], &[], &[])]);
+    let explanations = assertion.explain(&result, &bindings(&assertion)).unwrap();
+    assert_eq!(explanations.len(), 2);
+    assert!(explanations[0].is_none());
+    assert!(explanations[1].is_some());
+}
+
+#[test]
+fn test_explain_unbound_argument() {
+    let assertion = assertion(ASSERTION);
+    let mut cex = cex(&[1], &[], &[2]);
+    cex.remove("il_0");
+    assert_eq!(
+        assertion.explain_cex(&cex, &bindings(&assertion)),
+        Err(EvalError::UnboundVariable("il_0".to_string()))
+    );
+    // Named after the spec's arguments, every one of them must be bound
+    let named = Counterexample::new(vec![
+        (ElrondValue::L(vec![1]), "l1".to_string()),
+        (ElrondValue::L(vec![]), "l2".to_string()),
+        (ElrondValue::L(vec![2]), "l3".to_string()),
+    ]);
+    assert_eq!(
+        assertion.post_spec.explain(&named),
+        Err(EvalError::UnboundVariable("u".to_string()))
+    );
+}
+
+#[test]
+fn test_explain_shares_arguments_with_pre() {
+    // Only u = 1 satisfies the pre spec, and the post spec holds for it
+    let text = "let preds = [| \"mem\" |]
+
+let pre (l1 : list) (u : int) =
+  hd l1 u
+
+let post (l1 : list) (l2 : list) (u : int) =
+  mem l2 u
+";
+    let assertion = assertion(text);
+    let bindings = Bindings::by_name(&assertion.post_spec.0[..2]);
+    let cex = Counterexample::new(vec![
+        (ElrondValue::L(vec![1, 2]), "l1".to_string()),
+        (ElrondValue::L(vec![1]), "l2".to_string()),
+    ]);
+    assert_eq!(assertion.explain_cex(&cex, &bindings), Ok(None));
+    // The post spec alone is violated by u = 2
+    assert!(assertion
+        .post_spec
+        .explain_with(&cex, &bindings)
+        .unwrap()
+        .is_some());
+}
@@ -0,0 +1,142 @@
+mod common;
+
+use arwen_elrond_ipc::{
+    explain::Bindings,
+    gen::{Gen, GenConfig, GenError},
+    ipc::{ElrondKnownPredicate, ElrondTpedvar, ElrondTree, ElrondType, ElrondValue},
+    predicates::ElrondDatatype,
+};
+use common::assertion;
+
+fn tree_size(t: &ElrondTree) -> usize {
+    t.labels().len()
+}
+
+#[test]
+fn test_gen_deterministic() {
+    let args = [
+        ElrondTpedvar(ElrondType::IntList, "l".to_string()),
+        ElrondTpedvar(ElrondType::IntTree, "t".to_string()),
+        ElrondTpedvar(ElrondType::Int, "u".to_string()),
+    ];
+    let first = Gen::seeded(7).samples(10, &args, None).unwrap();
+    let second = Gen::seeded(7).samples(10, &args, None).unwrap();
+    let other = Gen::seeded(8).samples(10, &args, None).unwrap();
+    assert_eq!(first, second);
+    assert_ne!(first, other);
+}
+
+#[test]
+fn test_gen_bounds() {
+    let config = GenConfig {
+        min_size: 2,
+        max_size: 4,
+        min_int: -3,
+        max_int: 3,
+        ..GenConfig::default()
+    };
+    let mut gen = Gen::new(0, config);
+    for _ in 0..200 {
+        let l = gen.list();
+        assert!((2..=4).contains(&l.len()), "{l:?}");
+        assert!(l.iter().all(|x| (-3..=3).contains(x)), "{l:?}");
+        let t = gen.tree();
+        assert!((2..=4).contains(&tree_size(&t)), "{t}");
+        assert!(t.labels().iter().all(|x| (-3..=3).contains(x)), "{t}");
+    }
+}
+
+#[test]
+fn test_gen_index() {
+    let mut gen = Gen::seeded(0);
+    let indices: Vec<usize> = (0..200).map(|_| gen.index(3)).collect();
+    assert!(indices.iter().all(|i| *i < 3), "{indices:?}");
+    assert!((0..3).all(|i| indices.contains(&i)), "{indices:?}");
+    assert_eq!(gen.index(1), 0);
+}
+
+#[test]
+fn test_gen_generic() {
+    let mut gen = Gen::new(
+        0,
+        GenConfig {
+            generic: ElrondDatatype::Tree,
+            ..GenConfig::default()
+        },
+    );
+    let generic = ElrondType::Generic("Splayhp.t".to_string());
+    assert!(matches!(gen.value(&generic), Ok(ElrondValue::T(_))));
+    let arrow = ElrondType::Arrow(Box::new(ElrondType::Int), Box::new(ElrondType::Int));
+    assert_eq!(gen.value(&arrow), Err(GenError::Unsupported(arrow)));
+}
+
+#[test]
+fn test_gen_rejection_sampling() {
+    let assertion = assertion(
+        "let preds = [| \"sorted\"; \"mem\" |]
+
+let pre (l : Customstk.t) =
+  sorted l
+
+let post (l : Customstk.t) (u : int) =
+  true
+",
+    );
+    let pre = assertion.pre_spec.as_ref().unwrap();
+    let samples = Gen::seeded(1).samples(50, &pre.0, Some(pre)).unwrap();
+    for cex in samples {
+        let l = cex.get("l").unwrap().clone();
+        assert_eq!(ElrondKnownPredicate::sorted.apply(&[l]), Ok(true));
+    }
+}
+
+#[test]
+fn test_gen_exhausted() {
+    let assertion = assertion(
+        "let preds = [| \"mem\" |]
+
+let pre (l : list) (u : int) =
+  mem l u && u < u
+
+let post (l : list) (u : int) =
+  true
+",
+    );
+    let pre = assertion.pre_spec.as_ref().unwrap();
+    let config = GenConfig {
+        max_attempts: 10,
+        ..GenConfig::default()
+    };
+    assert_eq!(
+        Gen::new(0, config).sample(&pre.0, Some(pre)),
+        Err(GenError::Exhausted(10))
+    );
+}
+
+#[test]
+fn test_property_concat_spec() {
+    // concat's post spec holds whenever l3 really is l1 followed by l2
+    let assertion = assertion(
+        "let preds = [| \"mem\"; \"hd\" |]
+
+let post (l1 : Customstk.t) (l2 : Customstk.t) (l3 : Customstk.t) (u : int) =
+  iff (mem l3 u) (mem l1 u || mem l2 u) && implies (hd l3 u) (hd l1 u || hd l2 u)
+",
+    );
+    let inputs = &assertion.post_spec.0[..2];
+    let mut gen = Gen::seeded(42);
+    for mut cex in gen.samples(100, inputs, None).unwrap() {
+        let (Some(ElrondValue::L(l1)), Some(ElrondValue::L(l2))) = (cex.get("l1"), cex.get("l2"))
+        else {
+            unreachable!()
+        };
+        let l3 = [l1.as_slice(), l2.as_slice()].concat();
+        cex.insert("l3", ElrondValue::L(l3));
+        let bound = Bindings::by_name(&assertion.post_spec.0[..3]);
+        assert_eq!(
+            assertion.post_spec.explain_with(&cex, &bound),
+            Ok(None),
+            "{cex}"
+        );
+    }
+}
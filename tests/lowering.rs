@@ -0,0 +1,247 @@
+mod common;
+
+use arwen_elrond_ipc::{
+    assertion_parser::AssertionFileParser,
+    ipc::{
+        ArwenInput, ElrondAssertion, ElrondForallFormula, ElrondKnownPredicate, ElrondPred,
+        ElrondPredicates, ElrondSimpleExpr, ElrondSpec, ElrondTpedvar, ElrondType, FreeVar,
+    },
+    ipc_assertion::{Assertion, AssertionFile, AssertionType, Spec, Tpedvar},
+    lowering::{lift_assertion, lift_spec, lower_file, AbstractTypes, LoweringError},
+    predicates::ElrondDatatype,
+    vc::Vc,
+};
+use common::parse;
+
+const ASSERTION: &str = "let preds = [| \"mem\"; \"hd\" |]
+
+let post (l1 : Customstk.t) (l2 : Customstk.t) (u : int) (v : int) =
+  implies (hd l1 u) (mem l2 v || u == v)
+";
+
+fn var(ty: &ElrondType, name: &str) -> ElrondSimpleExpr {
+    ElrondSimpleExpr::Var(ty.clone(), name.to_string())
+}
+
+fn app(op: &str, args: Vec<ElrondSimpleExpr>) -> ElrondPred {
+    ElrondPred::Atom(ElrondSimpleExpr::Op(ElrondType::Bool, op.to_string(), args))
+}
+
+#[test]
+fn test_lower_resolves_types() {
+    let file = parse(ASSERTION);
+    let lowered = ElrondAssertion::try_from(&file).unwrap();
+
+    // `hd` only applies to lists, so `Customstk.t` is one
+    let stk = ElrondType::IntList;
+    let int = ElrondType::Int;
+    let expected = ElrondAssertion {
+        preds: ElrondPredicates(vec![
+            ElrondKnownPredicate::member,
+            ElrondKnownPredicate::head,
+        ]),
+        pre_spec: None,
+        post_spec: ElrondSpec(
+            vec![
+                ElrondTpedvar(stk.clone(), "l1".to_string()),
+                ElrondTpedvar(stk.clone(), "l2".to_string()),
+                ElrondTpedvar(int.clone(), "u".to_string()),
+                ElrondTpedvar(int.clone(), "v".to_string()),
+            ],
+            ElrondForallFormula(
+                vec![],
+                ElrondPred::Implies(
+                    Box::new(app("hd", vec![var(&stk, "l1"), var(&int, "u")])),
+                    Box::new(ElrondPred::Or(vec![
+                        app("mem", vec![var(&stk, "l2"), var(&int, "v")]),
+                        app("==", vec![var(&int, "u"), var(&int, "v")]),
+                    ])),
+                ),
+            ),
+        ),
+        types: AbstractTypes(vec![("Customstk.t".to_string(), ElrondDatatype::List)]),
+    };
+    assert_eq!(lowered, expected);
+}
+
+#[test]
+fn test_lowered_assertion_prints_abstract_types() {
+    let lowered = ElrondAssertion::try_from(&parse(ASSERTION)).unwrap();
+    assert_eq!(
+        lowered.post_spec.0[0],
+        ElrondTpedvar(ElrondType::IntList, "l1".to_string())
+    );
+    // The engine only parses the type names the source's signature declares
+    let written = ArwenInput::from(&lowered).read().unwrap();
+    assert!(written.contains("let post (l1 : Customstk.t) (l2 : Customstk.t) (u : int)"));
+    assert!(!written.contains(": list"));
+}
+
+#[test]
+fn test_lower_lift_round_trip() {
+    let file = parse(ASSERTION);
+    let types = AbstractTypes::default().resolve(&file).unwrap();
+    assert_eq!(
+        types,
+        AbstractTypes(vec![("Customstk.t".to_string(), ElrondDatatype::List)])
+    );
+    let lowered = lower_file(&file, &types).unwrap();
+    assert_eq!(lift_assertion(&lowered, &types).unwrap(), file);
+
+    // The lowered assertion keeps the names it was lowered with
+    let lifted = AssertionFile::try_from(&lowered).unwrap();
+    assert_eq!(lifted, file);
+    assert_eq!(ElrondAssertion::try_from(&lifted).unwrap(), lowered);
+
+    // Without names, lists are written as `list`, which lowers back to lists
+    let unnamed = ElrondAssertion {
+        types: AbstractTypes::default(),
+        ..lowered.clone()
+    };
+    let lifted = AssertionFile::try_from(&unnamed).unwrap();
+    assert!(lifted.to_string().contains("(l1 : list)"));
+    assert_eq!(
+        ElrondAssertion::try_from(&lifted).unwrap().post_spec,
+        lowered.post_spec
+    );
+}
+
+#[test]
+fn test_lower_unresolved_types() {
+    // `mem` applies to lists and trees alike
+    let ambiguous =
+        parse("let preds = [| \"mem\" |]\n\nlet post (t : Tree.t) (u : int) = mem t u\n");
+    let error = ElrondAssertion::try_from(&ambiguous).unwrap_err();
+    assert_eq!(
+        error,
+        LoweringError::UnresolvedType {
+            ty: "Tree.t".to_string(),
+            candidates: vec![ElrondDatatype::List, ElrondDatatype::Tree],
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "Tree.t could be a list or a tree; name it in the abstract types"
+    );
+    let types = AbstractTypes(vec![("Tree.t".to_string(), ElrondDatatype::Tree)]);
+    let lowered = lower_file(&ambiguous, &types).unwrap();
+    assert_eq!(lowered.post_spec.0[0].0, ElrondType::IntTree);
+
+    let conflicting = parse(
+        "let preds = [| \"hd\"; \"root\" |]\n\nlet post (t : Tree.t) (u : int) = hd t u && root t u\n",
+    );
+    assert_eq!(
+        ElrondAssertion::try_from(&conflicting)
+            .unwrap_err()
+            .to_string(),
+        "no datatype fits every use of Tree.t"
+    );
+
+    // Nothing constrains `Customstk.t`, so it is a list
+    let unconstrained =
+        parse("let preds = [| |]\n\nlet post (a : Customstk.t) (b : Customstk.t) = a == b\n");
+    let lowered = ElrondAssertion::try_from(&unconstrained).unwrap();
+    assert_eq!(lowered.post_spec.0[1].0, ElrondType::IntList);
+}
+
+#[test]
+fn test_lower_unbound_variable() {
+    let file = AssertionFileParser::new()
+        .parse("let preds = [| \"hd\" |]\n\nlet post (l : Customstk.t) = hd l w\n")
+        .unwrap();
+    assert_eq!(
+        ElrondAssertion::try_from(&file),
+        Err(LoweringError::UnboundVariable("w".to_string()))
+    );
+}
+
+#[test]
+fn test_lift_free_vars_and_ite() {
+    let stk = ElrondType::IntList;
+    let int = ElrondType::Int;
+    let mem = app("mem", vec![var(&stk, "l"), var(&int, "u")]);
+    let spec = ElrondSpec(
+        vec![ElrondTpedvar(stk.clone(), "l".to_string())],
+        ElrondForallFormula(
+            vec![FreeVar(int.clone(), "u".to_string())],
+            ElrondPred::Ite(
+                Box::new(mem.clone()),
+                Box::new(ElrondPred::True),
+                Box::new(ElrondPred::Not(Box::new(mem))),
+            ),
+        ),
+    );
+
+    let mem = Assertion::Predicate(
+        ElrondKnownPredicate::member,
+        vec!["l".to_string(), "u".to_string()],
+    );
+    assert_eq!(
+        lift_spec(
+            &spec,
+            &AbstractTypes(vec![("Customstk.t".to_string(), ElrondDatatype::List)])
+        )
+        .unwrap(),
+        Spec(
+            vec![
+                Tpedvar(
+                    AssertionType::Generic("Customstk.t".to_string()),
+                    "l".to_string()
+                ),
+                Tpedvar(AssertionType::Int, "u".to_string()),
+            ],
+            Assertion::And(vec![
+                Assertion::Implies(Box::new(mem.clone()), Box::new(Assertion::True)),
+                Assertion::Implies(
+                    Box::new(Assertion::Not(Box::new(mem.clone()))),
+                    Box::new(Assertion::Not(Box::new(mem))),
+                ),
+            ]),
+        )
+    );
+}
+
+#[test]
+fn test_lift_inferred_specs() {
+    let vc = Vc::from_file("_customstk_out/_beforeweakening.json").unwrap();
+    // The others test a bool argument or compare to a nested op, which assertion
+    // files cannot write
+    for name in [
+        "Customstk.top",
+        "Customstk.tail",
+        "Customstk.push",
+        "concat_post",
+    ] {
+        let spec = vc.spec(name).unwrap();
+        let lifted = lift_spec(spec, &AbstractTypes::default()).unwrap();
+        for Tpedvar(ty, _) in &lifted.0 {
+            assert!(["int", "list"].contains(&ty.to_string().as_str()), "{name}");
+        }
+    }
+}
+
+#[test]
+fn test_lift_unsupported() {
+    let tuple_spec = ElrondSpec(
+        vec![ElrondTpedvar(
+            ElrondType::Tuple(vec![ElrondType::Int]),
+            "p".to_string(),
+        )],
+        ElrondForallFormula(vec![], ElrondPred::True),
+    );
+    assert_eq!(
+        lift_spec(&tuple_spec, &AbstractTypes::default()),
+        Err(LoweringError::UnsupportedType(ElrondType::Tuple(vec![
+            ElrondType::Int
+        ])))
+    );
+
+    let unknown_op = ElrondSpec(
+        vec![ElrondTpedvar(ElrondType::Int, "u".to_string())],
+        ElrondForallFormula(vec![], app("succ", vec![var(&ElrondType::Int, "u")])),
+    );
+    assert!(matches!(
+        lift_spec(&unknown_op, &AbstractTypes::default()),
+        Err(LoweringError::UnsupportedExpr(_))
+    ));
+}
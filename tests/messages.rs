@@ -1,12 +1,16 @@
 use arwen_elrond_ipc::{
-    ipc::{ArwenMessage, ArwenSetup, ElrondKnownPredicate, ElrondPredicates},
+    ipc::{
+        ArwenInput, ArwenMessage, ArwenSetup, ElrondKnownPredicate, ElrondPredicates,
+        EnginePredicates, InferenceOptions,
+    },
+    predicates::ElrondDatatype,
     Elrond,
 };
 
 #[cfg(test)]
 use arwen_elrond_ipc::ipc::{
-    ElrondForallFormula, ElrondLiteral, ElrondMessage, ElrondPred, ElrondResult, ElrondSimpleExpr,
-    ElrondSpec, ElrondTpedvar, ElrondType, ElrondValue, FreeVar,
+    Counterexample, ElrondForallFormula, ElrondInferMode, ElrondLiteral, ElrondMessage, ElrondPred,
+    ElrondResult, ElrondSimpleExpr, ElrondSpec, ElrondTpedvar, ElrondType, ElrondValue, FreeVar,
 };
 
 // Make tests to check that we can properly receive Elrond Messages
@@ -323,7 +327,7 @@ make_test! {
 make_test! {
     test_result_cex_single,
     ArwenMessage::Message("Result (Cex [[x, [L [1; 2]]])".to_string()),
-    ElrondMessage::Message(serde_json::to_string(&ElrondResult::Cex(vec![vec![( ElrondValue::L(vec![1, 2]), "x".to_string())]])).unwrap())
+    ElrondMessage::Message(serde_json::to_string(&ElrondResult::Cex(vec![Counterexample::new(vec![( ElrondValue::L(vec![1, 2]), "x".to_string())])])).unwrap())
 }
 
 make_test! {
@@ -336,23 +340,50 @@ make_test! {
     ).unwrap())
 }
 
+make_test! {
+    test_infer_mode,
+    ArwenMessage::Message("Mode Full".to_string()),
+    ElrondMessage::Message(serde_json::to_string(&ElrondInferMode::Full).unwrap())
+}
+
+make_test! {
+    test_result_message,
+    ArwenMessage::Message("Result Consistent (Spec [])".to_string()),
+    ElrondMessage::Result(ElrondInferMode::Consistent, ElrondResult::Result(vec![]))
+}
+
 make_test! {
     test_elrond_predicates,
-    ArwenMessage::Message(serde_json::to_string(&ElrondPredicates(vec![ElrondKnownPredicate::list_member, ElrondKnownPredicate::list_order])).unwrap()),
-    ElrondMessage::Message(serde_json::to_string(&ElrondPredicates(vec![ElrondKnownPredicate::list_member, ElrondKnownPredicate::list_order])).unwrap())
+    ArwenMessage::Message(serde_json::to_string(&EnginePredicates { datatype: ElrondDatatype::List, predicates: ElrondPredicates(vec![ElrondKnownPredicate::member, ElrondKnownPredicate::order]) }).unwrap()),
+    ElrondMessage::Message(serde_json::to_string(&EnginePredicates { datatype: ElrondDatatype::List, predicates: ElrondPredicates(vec![ElrondKnownPredicate::member, ElrondKnownPredicate::order]) }).unwrap())
 }
 
 make_test! {
     test_setup,
     {
         let dir = "ADT-Lemma-Discovery".to_string();
-        let sourcefile = dir.clone() + "/data/customstk.ml";
-        let assertionfile = dir + "/data/customstk_assertion1.ml";
+        let sourcefile = ArwenInput::file(dir.clone() + "/data/customstk.ml");
+        let assertionfile = ArwenInput::file(dir + "/data/customstk_assertion1.ml");
+        let outputdir = "customstk_out".to_string();
+        let client_name = "concat".to_string();
+        let predicates = EnginePredicates { datatype: ElrondDatatype::List, predicates: ElrondPredicates(vec![        ElrondKnownPredicate::member,
+        ElrondKnownPredicate::head,]) };
+        let mode = ElrondInferMode::Weakening;
+        ArwenMessage::Setup(ArwenSetup {sourcefile, assertionfile, outputdir, client_name, predicates, mode, holes: None, options: InferenceOptions::default()})
+    },
+    ElrondMessage::Message("ADT-Lemma-Discovery/data/customstk.ml ADT-Lemma-Discovery/data/customstk_assertion1.ml customstk_out concat Weakening list_member list_head".to_string())
+}
+
+make_test! {
+    test_setup_inline,
+    {
+        let sourcefile = ArwenInput::file("ADT-Lemma-Discovery/data/customstk.ml");
+        let assertionfile = ArwenInput::inline("let preds = [| \"mem\" |]");
         let outputdir = "customstk_out".to_string();
         let client_name = "concat".to_string();
-        let predicates = ElrondPredicates(vec![        ElrondKnownPredicate::list_member,
-        ElrondKnownPredicate::list_head,]);
-        ArwenMessage::Setup(ArwenSetup {sourcefile, assertionfile, outputdir, client_name, predicates})
+        let predicates = EnginePredicates { datatype: ElrondDatatype::List, predicates: ElrondPredicates(vec![ElrondKnownPredicate::member]) };
+        let mode = ElrondInferMode::Consistent;
+        ArwenMessage::Setup(ArwenSetup {sourcefile, assertionfile, outputdir, client_name, predicates, mode, holes: None, options: InferenceOptions::default()})
     },
-    ElrondMessage::Message("ADT-Lemma-Discovery/data/customstk.ml ADT-Lemma-Discovery/data/customstk_assertion1.ml customstk_out concat list_member list_head".to_string())
+    ElrondMessage::Message("ADT-Lemma-Discovery/data/customstk.ml let preds = [| \"mem\" |] customstk_out concat Consistent list_member".to_string())
 }
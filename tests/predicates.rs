@@ -0,0 +1,222 @@
+use arwen_elrond_ipc::{
+    assertion_parser::AssertionFileParser,
+    ipc::{ElrondKnownPredicate, ElrondPredicates, ElrondType, EnginePredicates, UnknownPredicate},
+    predicates::ElrondDatatype,
+    typecheck::{parse_typed, TypeErrorKind},
+};
+use lalrpop_util::ParseError;
+
+#[test]
+fn test_names_parse_back() {
+    for p in ElrondKnownPredicate::ALL {
+        let (variant, surface) = p.names();
+        assert_eq!(variant.parse(), Ok(p));
+        assert_eq!(surface.parse(), Ok(p));
+        for datatype in p.signature().datatypes {
+            let engine = p.engine_name(*datatype).unwrap();
+            assert_eq!(ElrondKnownPredicate::try_from(engine), Ok(p));
+            assert_eq!(
+                ElrondKnownPredicate::from_engine_name(engine),
+                Some((p, *datatype))
+            );
+        }
+    }
+}
+
+#[test]
+fn test_engine_names_depend_on_datatype() {
+    let mem = ElrondKnownPredicate::member;
+    assert_eq!(mem.engine_name(ElrondDatatype::List), Some("list_member"));
+    assert_eq!(mem.engine_name(ElrondDatatype::Tree), Some("tree_member"));
+    assert_eq!(
+        ElrondKnownPredicate::head.engine_name(ElrondDatatype::Tree),
+        None
+    );
+    // A name for every datatype the signature allows, and no other
+    for p in ElrondKnownPredicate::ALL {
+        for datatype in [ElrondDatatype::List, ElrondDatatype::Tree] {
+            assert_eq!(
+                p.engine_name(datatype).is_some(),
+                p.signature().applies_to(datatype),
+                "{p} on {datatype}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_serde_uses_surface_names() {
+    for p in ElrondKnownPredicate::ALL {
+        let (variant, surface) = p.names();
+        assert_eq!(serde_json::to_string(&p).unwrap(), format!("\"{surface}\""));
+        let engine = p
+            .signature()
+            .datatypes
+            .iter()
+            .map(|dt| p.engine_name(*dt).unwrap());
+        for name in [variant, surface].into_iter().chain(engine) {
+            let parsed: ElrondKnownPredicate =
+                serde_json::from_str(&format!("\"{name}\"")).unwrap();
+            assert_eq!(parsed, p);
+        }
+    }
+}
+
+#[test]
+fn test_display_uses_surface_names() {
+    let preds = ElrondPredicates(vec![
+        ElrondKnownPredicate::member,
+        ElrondKnownPredicate::head,
+    ]);
+    assert_eq!(preds.to_string(), "let preds = [| \"mem\"; \"hd\" |]");
+    assert_eq!(serde_json::to_string(&preds).unwrap(), "[\"mem\",\"hd\"]");
+}
+
+#[test]
+fn test_engine_predicates_wire_format() {
+    let on = |datatype, predicates: &[ElrondKnownPredicate]| EnginePredicates {
+        datatype,
+        predicates: ElrondPredicates(predicates.to_vec()),
+    };
+    let list = on(
+        ElrondDatatype::List,
+        &[ElrondKnownPredicate::member, ElrondKnownPredicate::head],
+    );
+    let json = serde_json::to_string(&list).unwrap();
+    assert_eq!(json, "[\"list_member\",\"list_head\"]");
+    assert_eq!(
+        serde_json::from_str::<EnginePredicates>(&json).unwrap(),
+        list
+    );
+
+    let tree = on(
+        ElrondDatatype::Tree,
+        &[ElrondKnownPredicate::member, ElrondKnownPredicate::para],
+    );
+    let json = serde_json::to_string(&tree).unwrap();
+    assert_eq!(json, "[\"tree_member\",\"tree_parallel\"]");
+    assert_eq!(
+        serde_json::from_str::<EnginePredicates>(&json).unwrap(),
+        tree
+    );
+
+    let wrong = on(ElrondDatatype::Tree, &[ElrondKnownPredicate::head]);
+    assert_eq!(wrong.names(), Err(ElrondKnownPredicate::head));
+    assert!(serde_json::to_string(&wrong).is_err());
+    assert!(serde_json::from_str::<EnginePredicates>("[\"list_member\",\"tree_member\"]").is_err());
+}
+
+#[test]
+fn test_unknown_predicate() {
+    assert_eq!(
+        "not_a_pred".parse::<ElrondKnownPredicate>(),
+        Err(UnknownPredicate("not_a_pred".to_string()))
+    );
+    assert!(serde_json::from_str::<ElrondKnownPredicate>("\"not_a_pred\"").is_err());
+}
+
+#[test]
+fn test_program_parser_uses_name_table() {
+    let source = |preds: &str| {
+        format!("let preds = [| {preds} |]\nlet post (l : Customstk.t) (u : int) = hd l u")
+    };
+    let assertion = parse_typed(&source("\"hd\"; \"list_member\"")).unwrap();
+    assert_eq!(
+        assertion.preds,
+        ElrondPredicates(vec![
+            ElrondKnownPredicate::head,
+            ElrondKnownPredicate::member,
+        ])
+    );
+    let errors = parse_typed(&source("\"hd\"; \"not_a_pred\"")).unwrap_err();
+    assert_eq!(
+        errors[0].kind,
+        TypeErrorKind::Syntax("unknown predicate `not_a_pred`".to_string())
+    );
+}
+
+#[test]
+fn test_assertion_parser_uses_name_table() {
+    let source = |preds: &str, body: &str| {
+        format!("let preds = [| {preds} |]\nlet post (l : Customstk.t) (u : int) = {body}")
+    };
+    let file = AssertionFileParser::new()
+        .parse(&source("\"hd\"; \"list_member\"", "head l u"))
+        .unwrap();
+    assert_eq!(
+        file.preds.0,
+        vec![ElrondKnownPredicate::head, ElrondKnownPredicate::member]
+    );
+    for (preds, body, name) in [
+        ("\"not_a_pred\"", "hd l u", "not_a_pred"),
+        ("\"hd\"", "notapred l u", "notapred"),
+    ] {
+        assert_eq!(
+            AssertionFileParser::new().parse(&source(preds, body)),
+            Err(ParseError::User {
+                error: UnknownPredicate(name.to_string())
+            })
+        );
+    }
+}
+
+#[test]
+fn test_signatures() {
+    let table = [
+        (ElrondKnownPredicate::length, "len : list -> int -> bool"),
+        (ElrondKnownPredicate::sorted, "sorted : list -> bool"),
+        (
+            ElrondKnownPredicate::member,
+            "mem : (list | tree) -> int -> bool",
+        ),
+        (ElrondKnownPredicate::head, "hd : list -> int -> bool"),
+        (
+            ElrondKnownPredicate::order,
+            "ord : list -> int -> int -> bool",
+        ),
+        (
+            ElrondKnownPredicate::once,
+            "once : (list | tree) -> int -> bool",
+        ),
+        (
+            ElrondKnownPredicate::left,
+            "left : tree -> int -> int -> bool",
+        ),
+        (
+            ElrondKnownPredicate::right,
+            "right : tree -> int -> int -> bool",
+        ),
+        (
+            ElrondKnownPredicate::para,
+            "para : tree -> int -> int -> bool",
+        ),
+        (
+            ElrondKnownPredicate::ance,
+            "ance : tree -> int -> int -> bool",
+        ),
+        (ElrondKnownPredicate::root, "root : tree -> int -> bool"),
+    ];
+    assert_eq!(table.len(), ElrondKnownPredicate::ALL.len());
+    for (p, expected) in table {
+        assert_eq!(p.signature().to_string(), expected);
+        assert!(p.signature().description.starts_with(p.surface_name()));
+    }
+}
+
+#[test]
+fn test_signature_arg_types() {
+    let ord = ElrondKnownPredicate::order.signature();
+    assert_eq!(ord.arity(), 3);
+    assert_eq!(
+        ord.arg_types(ElrondDatatype::List),
+        Some(vec![ElrondType::IntList, ElrondType::Int, ElrondType::Int])
+    );
+    assert_eq!(ord.arg_types(ElrondDatatype::Tree), None);
+
+    let mem = ElrondKnownPredicate::member.signature();
+    assert!(mem.applies_to(ElrondDatatype::List) && mem.applies_to(ElrondDatatype::Tree));
+    assert_eq!(
+        ElrondDatatype::from_type(&ElrondType::IntTreeB),
+        Some(ElrondDatatype::Tree)
+    );
+}
@@ -0,0 +1,222 @@
+mod common;
+
+use arwen_elrond_ipc::{
+    assertion_parser::AssertionParser,
+    ipc::{ElrondAssertion, ElrondPred, ElrondSimpleExpr, ElrondType},
+    ipc_assertion::{Assertion, AssertionOp},
+    pretty::{PrettyConfig, PrettyStyle},
+};
+use common::{parse, vc, ASSERTION_WITH_PRE};
+
+fn math() -> PrettyConfig {
+    PrettyConfig {
+        style: PrettyStyle::Math,
+        ..PrettyConfig::default()
+    }
+}
+
+fn narrow(style: PrettyStyle) -> PrettyConfig {
+    PrettyConfig {
+        width: 40,
+        style,
+        ..PrettyConfig::default()
+    }
+}
+
+fn flat() -> PrettyConfig {
+    PrettyConfig::flat(PrettyStyle::OCaml)
+}
+
+fn name(s: &str) -> String {
+    s.to_string()
+}
+
+fn mem(l: &str, u: &str) -> Assertion {
+    Assertion::Predicate("mem".parse().unwrap(), vec![name(l), name(u)])
+}
+
+fn not(a: Assertion) -> Assertion {
+    Assertion::Not(Box::new(a))
+}
+
+#[test]
+fn test_pretty_not_parenthesizes_operand() {
+    let cases = [
+        (
+            not(Assertion::And(vec![mem("a", "u"), mem("b", "u")])),
+            "!(mem a u && mem b u)",
+        ),
+        (
+            Assertion::And(vec![not(mem("a", "u")), mem("b", "u")]),
+            "!mem a u && mem b u",
+        ),
+        (
+            not(Assertion::Op(AssertionOp::Ne, name("u"), name("v"))),
+            "!(u != v)",
+        ),
+        (not(not(Assertion::True)), "!!true"),
+    ];
+    for (assertion, expected) in cases {
+        assert_eq!(assertion.pretty(&flat()), expected);
+        assert_eq!(assertion.to_string(), expected);
+    }
+}
+
+#[test]
+fn test_pretty_minimal_parentheses() {
+    let cases = [
+        (
+            Assertion::Or(vec![
+                Assertion::And(vec![mem("a", "u"), mem("b", "u")]),
+                mem("c", "u"),
+            ]),
+            "mem a u && mem b u || mem c u",
+        ),
+        (
+            Assertion::And(vec![
+                Assertion::Or(vec![mem("a", "u"), mem("b", "u")]),
+                mem("c", "u"),
+            ]),
+            "(mem a u || mem b u) && mem c u",
+        ),
+        (
+            Assertion::And(vec![
+                Assertion::And(vec![mem("a", "u"), mem("b", "u")]),
+                mem("c", "u"),
+            ]),
+            "(mem a u && mem b u) && mem c u",
+        ),
+        (
+            Assertion::And(vec![
+                Assertion::Op(AssertionOp::Eq, name("u"), name("v")),
+                Assertion::Implies(Box::new(mem("a", "u")), Box::new(mem("b", "v"))),
+            ]),
+            "u == v && implies (mem a u) (mem b v)",
+        ),
+    ];
+    for (assertion, expected) in cases {
+        assert_eq!(assertion.pretty(&flat()), expected);
+    }
+}
+
+#[test]
+fn test_pretty_math_precedence() {
+    let parser = AssertionParser::new();
+    let cases = [
+        (
+            "implies (implies (mem a u) (mem b u)) (mem c u)",
+            "(mem a u ⇒ mem b u) ⇒ mem c u",
+        ),
+        (
+            "implies (mem a u) (implies (mem b u) (mem c u))",
+            "mem a u ⇒ mem b u ⇒ mem c u",
+        ),
+        (
+            "iff (implies (mem a u) (mem b u)) (u == v)",
+            "mem a u ⇒ mem b u ⇔ u = v",
+        ),
+        (
+            "iff (iff (mem a u) (mem b u)) (u <= v)",
+            "(mem a u ⇔ mem b u) ⇔ u ≤ v",
+        ),
+    ];
+    for (input, expected) in cases {
+        let assertion = parser.parse(input).unwrap();
+        assert_eq!(
+            assertion.pretty(&PrettyConfig::flat(PrettyStyle::Math)),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_pretty_elrond_pred() {
+    let var = |ty, s: &str| ElrondSimpleExpr::Var(ty, s.to_string());
+    let eq = ElrondPred::Atom(ElrondSimpleExpr::Op(
+        ElrondType::Bool,
+        "==".to_string(),
+        vec![var(ElrondType::Int, "u"), var(ElrondType::Int, "v")],
+    ));
+    let member = ElrondPred::Atom(ElrondSimpleExpr::Op(
+        ElrondType::Bool,
+        "list_member".to_string(),
+        vec![var(ElrondType::IntList, "l"), var(ElrondType::Int, "u")],
+    ));
+    let pred = ElrondPred::Or(vec![
+        ElrondPred::Not(Box::new(eq.clone())),
+        ElrondPred::And(vec![
+            member.clone(),
+            ElrondPred::Ite(
+                Box::new(eq),
+                Box::new(ElrondPred::True),
+                Box::new(ElrondPred::Not(Box::new(member))),
+            ),
+        ]),
+    ]);
+    insta::assert_snapshot!(format!(
+        "{}\n{}",
+        pred.pretty(&flat()),
+        pred.pretty(&PrettyConfig::flat(PrettyStyle::Math))
+    ));
+}
+
+#[test]
+fn test_pretty_inferred_spec() {
+    let vc = vc();
+    let push = vc.spec("Customstk.push").unwrap();
+    insta::assert_snapshot!(format!(
+        "{}\n\n{}",
+        push.pretty(&PrettyConfig::default()),
+        push.pretty(&math())
+    ));
+}
+
+#[test]
+fn test_pretty_inferred_spec_narrow() {
+    let vc = vc();
+    let push = vc.spec("Customstk.push").unwrap();
+    insta::assert_snapshot!(format!(
+        "{}\n\n{}",
+        push.pretty(&narrow(PrettyStyle::OCaml)),
+        push.pretty(&narrow(PrettyStyle::Math))
+    ));
+}
+
+#[test]
+fn test_pretty_fits_width() {
+    let vc = vc();
+    for width in [40, 60, 80] {
+        let config = PrettyConfig {
+            width,
+            ..PrettyConfig::default()
+        };
+        for name in vc.applied_specs() {
+            let Some(spec) = vc.spec(name) else { continue };
+            for line in spec.pretty(&config).lines() {
+                // A single atom may be longer than the width; nothing else is
+                assert!(
+                    line.chars().count() <= width || !line.trim().contains(' '),
+                    "{name} at width {width}: {line:?}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_pretty_assertion_file() {
+    let file = parse(ASSERTION_WITH_PRE);
+    insta::assert_snapshot!(format!(
+        "{}\n{}\n{}",
+        file.pretty(&PrettyConfig::default()),
+        file.pretty(&narrow(PrettyStyle::OCaml)),
+        file.pretty(&math())
+    ));
+}
+
+#[test]
+fn test_pretty_elrond_assertion() {
+    let file = parse(ASSERTION_WITH_PRE);
+    let assertion = ElrondAssertion::try_from(&file).unwrap();
+    insta::assert_snapshot!(assertion.pretty(&PrettyConfig::default()));
+}
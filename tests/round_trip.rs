@@ -0,0 +1,186 @@
+use arwen_elrond_ipc::{
+    assertion_parser::{AssertionFileParser, AssertionParser},
+    gen::Gen,
+    ipc_assertion::{
+        Assertion, AssertionFile, AssertionOp, AssertionPredicates, AssertionType, Pred, Spec,
+        Tpedvar,
+    },
+    pretty::PrettyConfig,
+};
+
+const SEEDS: u64 = 200;
+
+const PREDS: [&str; 11] = [
+    "len", "sorted", "mem", "hd", "ord", "once", "left", "right", "para", "ance", "root",
+];
+
+const NAMES: [&str; 6] = ["l1", "l2", "u", "v", "x", "t0"];
+
+const OPS: [AssertionOp; 6] = [
+    AssertionOp::Eq,
+    AssertionOp::Le,
+    AssertionOp::Ge,
+    AssertionOp::Ne,
+    AssertionOp::Lt,
+    AssertionOp::Gt,
+];
+
+/// Random ASTs for the round trip. With `degenerate`, `And` and `Or` may have fewer
+/// than two operands, which has no exact syntax.
+struct AstGen {
+    gen: Gen,
+    degenerate: bool,
+}
+
+impl AstGen {
+    fn new(seed: u64, degenerate: bool) -> Self {
+        AstGen {
+            gen: Gen::seeded(seed),
+            degenerate,
+        }
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        self.gen.index(n)
+    }
+
+    fn pick<T: Clone>(&mut self, items: &[T]) -> T {
+        items[self.below(items.len())].clone()
+    }
+
+    fn name(&mut self) -> String {
+        self.pick(&NAMES).to_string()
+    }
+
+    fn pred(&mut self) -> Pred {
+        self.pick(&PREDS).parse().unwrap()
+    }
+
+    fn ty(&mut self) -> AssertionType {
+        match self.below(4) {
+            0 => AssertionType::Int,
+            1 => AssertionType::Bool,
+            2 => AssertionType::Generic("Customstk.t".to_string()),
+            _ => AssertionType::Generic("T".to_string()),
+        }
+    }
+
+    fn assertion(&mut self, depth: usize) -> Assertion {
+        let choices = if depth == 0 { 3 } else { 8 };
+        match self.below(choices) {
+            0 => Assertion::True,
+            1 => {
+                let pred = self.pred();
+                let args = (0..1 + self.below(3)).map(|_| self.name()).collect();
+                Assertion::Predicate(pred, args)
+            }
+            2 => Assertion::Op(self.pick(&OPS), self.name(), self.name()),
+            3 => Assertion::Not(Box::new(self.assertion(depth - 1))),
+            4 => Assertion::Implies(
+                Box::new(self.assertion(depth - 1)),
+                Box::new(self.assertion(depth - 1)),
+            ),
+            5 => Assertion::Iff(
+                Box::new(self.assertion(depth - 1)),
+                Box::new(self.assertion(depth - 1)),
+            ),
+            n => {
+                let len = if self.degenerate {
+                    self.below(4)
+                } else {
+                    2 + self.below(3)
+                };
+                let operands = (0..len).map(|_| self.assertion(depth - 1)).collect();
+                if n == 6 {
+                    Assertion::And(operands)
+                } else {
+                    Assertion::Or(operands)
+                }
+            }
+        }
+    }
+
+    fn spec(&mut self) -> Spec {
+        let args = (0..self.below(4))
+            .map(|_| Tpedvar(self.ty(), self.name()))
+            .collect();
+        Spec(args, self.assertion(4))
+    }
+
+    fn file(&mut self) -> AssertionFile {
+        let preds = (0..self.below(4)).map(|_| self.pred()).collect();
+        let pre_spec = (self.below(2) == 0).then(|| self.spec());
+        AssertionFile {
+            preds: AssertionPredicates(preds),
+            pre_spec,
+            post_spec: self.spec(),
+        }
+    }
+}
+
+fn parse_file(text: &str) -> AssertionFile {
+    AssertionFileParser::new()
+        .parse(text)
+        .unwrap_or_else(|e| panic!("could not parse\n{text}\n{e}"))
+}
+
+#[test]
+fn test_round_trip_display() {
+    for seed in 0..SEEDS {
+        let file = AstGen::new(seed, false).file();
+        assert_eq!(parse_file(&file.to_string()), file, "seed {seed}");
+    }
+}
+
+#[test]
+fn test_round_trip_pretty() {
+    for seed in 0..SEEDS {
+        let file = AstGen::new(seed, false).file();
+        for width in [20, 40, 80] {
+            let config = PrettyConfig {
+                width,
+                ..PrettyConfig::default()
+            };
+            let text = file.pretty(&config);
+            assert_eq!(parse_file(&text), file, "seed {seed}, width {width}");
+        }
+    }
+}
+
+#[test]
+fn test_round_trip_assertion() {
+    let parser = AssertionParser::new();
+    for seed in 0..SEEDS {
+        let assertion = AstGen::new(seed, false).assertion(5);
+        let text = assertion.to_string();
+        assert_eq!(
+            parser.parse(&text).unwrap(),
+            assertion,
+            "seed {seed}: {text}"
+        );
+    }
+}
+
+#[test]
+fn test_degenerate_junctions_print_stably() {
+    for seed in 0..SEEDS {
+        let file = AstGen::new(seed, true).file();
+        let text = file.to_string();
+        let reparsed = parse_file(&text);
+        assert_eq!(reparsed.to_string(), text, "seed {seed}");
+        assert_eq!(parse_file(&reparsed.to_string()), reparsed, "seed {seed}");
+    }
+}
+
+#[test]
+fn test_round_trip_data_shape() {
+    let text = "let preds = [| \"mem\"; \"hd\" |]
+let pre (l1 : Customstk.t) (u : int) =
+  !mem l1 u && (hd l1 u || u == v)
+
+let post (l1 : Customstk.t) (u : int) =
+  implies (!(u != v)) (mem l1 u || hd l1 u && !hd l1 v)
+";
+    let file = parse_file(text);
+    assert_eq!(file.to_string(), text);
+}
@@ -0,0 +1,288 @@
+mod common;
+
+use arwen_elrond_ipc::{
+    ipc::{
+        ArwenInput, ArwenSetup, ElrondHoles, ElrondInferMode, ElrondKnownPredicate,
+        ElrondPredicates, EnginePredicates, InferenceOptions,
+    },
+    predicates::ElrondDatatype,
+    setup::{SetupError, SourceSignature},
+    usage::PredicateIssue,
+};
+use common::ASSERTION;
+
+const SIGNATURE_SOURCE: &str = "module type Customstk = sig
+  type t
+  val is_empty : t -> bool
+  val push : int -> t -> t
+  val top : t -> int
+  val tail : t -> t
+end
+
+let rec concat (s1 : Customstk.t) (s2 : Customstk.t) : Customstk.t =
+  if Customstk.is_empty s1 then s2
+  else Customstk.push (Customstk.top s1) (concat (Customstk.tail s1) s2)
+";
+
+const CLIENT_SOURCE: &str = "let rec concat (s1 : Customstk.t) (s2 : Customstk.t) : Customstk.t =
+  if Customstk.is_empty s1 then s2
+  else Customstk.push (Customstk.top s1) (concat (Customstk.tail s1) s2)
+";
+
+fn setup(source: &str, holes: &[&str], trusted: &[&str]) -> ArwenSetup {
+    ArwenSetup {
+        sourcefile: ArwenInput::inline(source),
+        assertionfile: ArwenInput::inline(ASSERTION),
+        outputdir: "customstk_out".to_string(),
+        client_name: "concat".to_string(),
+        predicates: EnginePredicates {
+            datatype: ElrondDatatype::List,
+            predicates: ElrondPredicates(vec![]),
+        },
+        mode: ElrondInferMode::Consistent,
+        holes: Some(ElrondHoles {
+            holes: holes.iter().map(ToString::to_string).collect(),
+            trusted: trusted.iter().map(ToString::to_string).collect(),
+        }),
+        options: InferenceOptions::default(),
+    }
+}
+
+#[test]
+fn test_signature_from_sig_block() {
+    assert_eq!(
+        SourceSignature::from_source(SIGNATURE_SOURCE).functions,
+        vec![
+            "Customstk.is_empty",
+            "Customstk.push",
+            "Customstk.top",
+            "Customstk.tail"
+        ]
+    );
+}
+
+#[test]
+fn test_signature_from_client_calls() {
+    assert_eq!(
+        SourceSignature::from_source(CLIENT_SOURCE).functions,
+        vec![
+            "Customstk.is_empty",
+            "Customstk.push",
+            "Customstk.top",
+            "Customstk.tail"
+        ]
+    );
+}
+
+#[test]
+fn test_validate_holes() {
+    let setup = setup(
+        SIGNATURE_SOURCE,
+        &["Customstk.push", "Customstk.tail"],
+        &["Customstk.is_empty"],
+    );
+    assert_eq!(setup.validate(), Ok(vec![]));
+}
+
+#[test]
+fn test_validate_default_holes() {
+    assert_eq!(setup(SIGNATURE_SOURCE, &[], &[]).validate(), Ok(vec![]));
+}
+
+#[test]
+fn test_validate_unknown_hole() {
+    let setup = setup(SIGNATURE_SOURCE, &["Customstk.pop"], &["Stack.top"]);
+    assert_eq!(
+        setup.validate(),
+        Err(vec![
+            SetupError::UnknownFunction("Customstk.pop".to_string()),
+            SetupError::UnknownFunction("Stack.top".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn test_validate_hole_and_trusted() {
+    let setup = setup(SIGNATURE_SOURCE, &["Customstk.top"], &["Customstk.top"]);
+    assert_eq!(
+        setup.validate(),
+        Err(vec![
+            SetupError::HoleAndTrusted("Customstk.top".to_string()),
+            SetupError::NoHoles,
+        ])
+    );
+}
+
+#[test]
+fn test_validate_all_trusted() {
+    let setup = setup(
+        CLIENT_SOURCE,
+        &[],
+        &[
+            "Customstk.is_empty",
+            "Customstk.push",
+            "Customstk.top",
+            "Customstk.tail",
+        ],
+    );
+    assert_eq!(setup.validate(), Err(vec![SetupError::NoHoles]));
+}
+
+#[test]
+fn test_validate_missing_source() {
+    let mut setup = setup(SIGNATURE_SOURCE, &[], &[]);
+    setup.sourcefile = ArwenInput::file("does/not/exist.ml");
+    assert!(matches!(
+        setup.validate().unwrap_err().as_slice(),
+        [SetupError::Input(_)]
+    ));
+}
+
+#[test]
+fn test_validate_options() {
+    let mut setup = setup(SIGNATURE_SOURCE, &[], &[]);
+    setup.options.bound = 0;
+    setup.options.phase_timeout_secs = Some(0);
+    assert_eq!(
+        setup.validate(),
+        Err(vec![
+            SetupError::InvalidOption("bound must be at least 1".to_string()),
+            SetupError::InvalidOption("phase_timeout_secs must be at least 1".to_string()),
+        ])
+    );
+
+    // Options the stub cannot pass on are rejected rather than ignored
+    setup.options = InferenceOptions {
+        max_quantified_vars: Some(2),
+        sample_budget: Some(1000),
+        phase_timeout_secs: Some(60),
+        ..InferenceOptions::default()
+    };
+    assert_eq!(
+        setup.validate(),
+        Err(vec![
+            SetupError::InvalidOption(
+                "max_quantified_vars is not supported by the stub yet; leave it unset".to_string()
+            ),
+            SetupError::InvalidOption(
+                "sample_budget is not supported by the stub yet; leave it unset".to_string()
+            ),
+        ])
+    );
+}
+
+#[test]
+fn test_options_partial_json() {
+    let options: InferenceOptions =
+        serde_json::from_str("{\"seed\": 7, \"phase_timeout_secs\": 30}").unwrap();
+    assert_eq!(
+        options,
+        InferenceOptions {
+            phase_timeout_secs: Some(30),
+            seed: 7,
+            ..InferenceOptions::default()
+        }
+    );
+    // Larger seeds would not fit the stub's int
+    assert!(serde_json::from_str::<InferenceOptions>("{\"seed\": 4294967296}").is_err());
+}
+
+#[test]
+fn test_validate_predicate_arity() {
+    let mut setup = setup(SIGNATURE_SOURCE, &[], &[]);
+    setup.assertionfile = ArwenInput::inline(
+        "let preds = [| \"mem\"; \"ord\" |]
+
+let post (l1 : Customstk.t) (l2 : Customstk.t) (u : int) =
+  implies (mem l1 u u) (ord l2 u)
+",
+    );
+    assert_eq!(
+        setup.validate(),
+        Err(vec![
+            SetupError::Arity {
+                predicate: ElrondKnownPredicate::member,
+                expected: 2,
+                found: 3
+            },
+            SetupError::Arity {
+                predicate: ElrondKnownPredicate::order,
+                expected: 3,
+                found: 2
+            },
+        ])
+    );
+}
+
+#[test]
+fn test_validate_declared_predicates() {
+    let mut setup = setup(SIGNATURE_SOURCE, &[], &[]);
+    setup.assertionfile = ArwenInput::inline(
+        "let preds = [| \"mem\"; \"once\" |]
+
+let post (l1 : Customstk.t) (u : int) =
+  implies (mem l1 u) (hd l1 u || sorted l1)
+",
+    );
+    setup.predicates.predicates = ElrondPredicates(vec![ElrondKnownPredicate::head]);
+    assert_eq!(
+        setup.validate(),
+        Err(vec![SetupError::Predicate(PredicateIssue::Undeclared {
+            spec: "post".to_string(),
+            predicate: ElrondKnownPredicate::sorted,
+            count: 1
+        })])
+    );
+    // An unused declaration alone is only a warning
+    setup.predicates.predicates = ElrondPredicates(vec![
+        ElrondKnownPredicate::head,
+        ElrondKnownPredicate::sorted,
+    ]);
+    assert_eq!(
+        setup.validate(),
+        Ok(vec![PredicateIssue::Unused(ElrondKnownPredicate::once)])
+    );
+}
+
+#[test]
+fn test_validate_predicate_datatype() {
+    let mut setup = setup(SIGNATURE_SOURCE, &[], &[]);
+    setup.predicates = EnginePredicates {
+        datatype: ElrondDatatype::Tree,
+        predicates: ElrondPredicates(vec![
+            ElrondKnownPredicate::member,
+            ElrondKnownPredicate::head,
+        ]),
+    };
+    assert_eq!(
+        setup.validate().unwrap_err()[0],
+        SetupError::Datatype(ElrondKnownPredicate::head, ElrondDatatype::Tree)
+    );
+}
+
+#[test]
+fn test_validate_weakening() {
+    let mut setup = setup(SIGNATURE_SOURCE, &[], &[]);
+    setup.mode = ElrondInferMode::Weakening;
+    // The specs the engine saved for customstk_out are checked in
+    assert_eq!(setup.consistent_specs(), "_customstk_out/_consistent.json");
+    assert_eq!(setup.validate(), Ok(vec![]));
+
+    setup.outputdir = "missing_out".to_string();
+    assert_eq!(
+        setup.validate(),
+        Err(vec![SetupError::MissingConsistentSpecs(
+            "_missing_out/_consistent.json".to_string()
+        )])
+    );
+}
+
+#[test]
+fn test_validate_unparsable_assertion() {
+    let mut setup = setup(SIGNATURE_SOURCE, &[], &[]);
+    setup.assertionfile = ArwenInput::inline("let post = mem");
+    assert!(matches!(
+        setup.validate().unwrap_err().as_slice(),
+        [SetupError::Assertion(_)]
+    ));
+}
@@ -0,0 +1,118 @@
+mod common;
+
+use arwen_elrond_ipc::{
+    explain::Bindings,
+    ipc::{Counterexample, ElrondResult, ElrondTree, ElrondValue},
+};
+use common::{assertion, vc, ASSERTION};
+
+fn list(name: &str, l: &[i64]) -> (ElrondValue, String) {
+    (ElrondValue::L(l.to_vec()), name.to_string())
+}
+
+#[test]
+fn test_shrink_dropped_element() {
+    // concat forgot the last element of l1
+    let cex = Counterexample::new(vec![
+        list("l1", &[5, 7, 12]),
+        list("l2", &[3, 9]),
+        list("l3", &[5, 7, 3, 9]),
+        list("il_0", &[7, 12]),
+    ]);
+    let assertion = assertion(ASSERTION);
+    let bindings = Bindings::by_name(&assertion.post_spec.0[..3]);
+    let shrunk = assertion
+        .post_spec
+        .shrink_with(&cex, &bindings)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(shrunk.original, cex);
+    assert!(assertion
+        .post_spec
+        .explain_with(&shrunk.shrunk, &bindings)
+        .unwrap()
+        .is_some());
+    insta::assert_snapshot!(shrunk);
+}
+
+#[test]
+fn test_shrink_engine_counterexample() {
+    // As the engine reports it, over the client's variables rather than l1 l2 l3
+    let cex = Counterexample::new(vec![
+        list("s1", &[5, 7, 12]),
+        list("s2", &[3, 9]),
+        (ElrondValue::I(5), "i_0".to_string()),
+        list("il_2", &[7, 12]),
+        list("il_1", &[7, 3, 9]),
+        list("il_0", &[5, 7, 3, 9]),
+    ]);
+    let assertion = assertion(ASSERTION);
+    let vc = vc();
+    let bindings = Bindings::post_call(&assertion.post_spec.0, &vc).unwrap();
+    let result = ElrondResult::Cex(vec![cex]);
+    let shrunk = assertion.shrink(&result, &bindings).unwrap();
+    let shrunk = shrunk[0].as_ref().unwrap();
+
+    // Only the variables of the post call are kept, and they still violate it
+    assert_eq!(
+        shrunk.shrunk.names().collect::<Vec<_>>(),
+        ["il_0", "s1", "s2"]
+    );
+    assert!(assertion
+        .explain_cex(&shrunk.shrunk, &bindings)
+        .unwrap()
+        .is_some());
+    insta::assert_snapshot!(shrunk);
+}
+
+#[test]
+fn test_shrink_keeps_pre_spec() {
+    let text = "let preds = [| \"mem\" |]
+
+let pre (l1 : list) (u : int) =
+  mem l1 u
+
+let post (l1 : list) (l2 : list) (u : int) =
+  mem l2 u
+";
+    let cex = Counterexample::new(vec![list("l1", &[4, 6]), list("l2", &[2])]);
+    let result = ElrondResult::Cex(vec![cex.clone()]);
+    let assertion = assertion(text);
+    let bindings = Bindings::by_name(&assertion.post_spec.0[..2]);
+    let shrunk = assertion.shrink(&result, &bindings).unwrap();
+    let shrunk = shrunk[0].as_ref().unwrap();
+
+    // l1 must stay non-empty for some u to satisfy the pre spec
+    assert_eq!(shrunk.shrunk.get("l1"), Some(&ElrondValue::L(vec![0])));
+    assert_eq!(shrunk.shrunk.get("l2"), Some(&ElrondValue::L(vec![])));
+}
+
+#[test]
+fn test_shrink_not_violated() {
+    let cex = Counterexample::new(vec![list("l1", &[1]), list("l2", &[]), list("l3", &[1])]);
+    let spec = assertion(ASSERTION).post_spec;
+    let bindings = Bindings::by_name(&spec.0[..3]);
+    assert_eq!(spec.shrink_with(&cex, &bindings), Ok(None));
+}
+
+#[test]
+fn test_shrink_tree() {
+    let spec = assertion(
+        "let preds = [| \"mem\"; \"root\" |]
+
+let post (t : Tree.t) (u : int) =
+  implies (mem t u) (root t u)
+",
+    )
+    .post_spec;
+    let t = ElrondTree::node(
+        8,
+        ElrondTree::node(3, ElrondTree::singleton(1), ElrondTree::Leaf),
+        ElrondTree::singleton(9),
+    );
+    let cex = Counterexample::new(vec![(ElrondValue::T(t), "t".to_string())]);
+    let bindings = Bindings::by_name(&spec.0[..1]);
+    let shrunk = spec.shrink_with(&cex, &bindings).unwrap().unwrap();
+    insta::assert_snapshot!(shrunk);
+}
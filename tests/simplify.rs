@@ -0,0 +1,217 @@
+mod common;
+
+use arwen_elrond_ipc::{
+    eval::Env,
+    ipc::{ElrondPred, ElrondSimpleExpr, ElrondType, ElrondValue},
+    simplify::NormalForm,
+};
+use common::vc;
+use itertools::Itertools;
+
+const FORMS: [NormalForm; 5] = [
+    NormalForm::Simplified,
+    NormalForm::IteFree,
+    NormalForm::Nnf,
+    NormalForm::Cnf,
+    NormalForm::Dnf,
+];
+
+fn var(name: &str) -> ElrondPred {
+    ElrondPred::Atom(ElrondSimpleExpr::Var(ElrondType::Bool, name.to_string()))
+}
+
+fn not(p: ElrondPred) -> ElrondPred {
+    ElrondPred::Not(Box::new(p))
+}
+
+fn falsity() -> ElrondPred {
+    not(ElrondPred::True)
+}
+
+/// Whether `p1` and `p2` agree under every assignment to `p`, `q` and `r`.
+fn equivalent(p1: &ElrondPred, p2: &ElrondPred) -> bool {
+    (0..3)
+        .map(|_| [false, true])
+        .multi_cartesian_product()
+        .all(|values| {
+            let env: Env = ["p", "q", "r"]
+                .iter()
+                .zip(values)
+                .map(|(name, b)| (name.to_string(), ElrondValue::B(b)))
+                .collect();
+            p1.eval(&env).unwrap() == p2.eval(&env).unwrap()
+        })
+}
+
+fn is_literal(p: &ElrondPred) -> bool {
+    match p {
+        ElrondPred::True | ElrondPred::Atom(_) => true,
+        ElrondPred::Not(p) => matches!(**p, ElrondPred::True | ElrondPred::Atom(_)),
+        _ => false,
+    }
+}
+
+fn is_nnf(p: &ElrondPred) -> bool {
+    match p {
+        ElrondPred::And(p_vec) | ElrondPred::Or(p_vec) => p_vec.iter().all(is_nnf),
+        p => is_literal(p),
+    }
+}
+
+fn is_ite_free(p: &ElrondPred) -> bool {
+    match p {
+        ElrondPred::True | ElrondPred::Atom(_) => true,
+        ElrondPred::Ite(..) => false,
+        ElrondPred::Not(p) => is_ite_free(p),
+        ElrondPred::Implies(p1, p2) | ElrondPred::Iff(p1, p2) => is_ite_free(p1) && is_ite_free(p2),
+        ElrondPred::And(p_vec) | ElrondPred::Or(p_vec) => p_vec.iter().all(is_ite_free),
+    }
+}
+
+/// Whether `p` is an `outer` of `inner`s of literals, allowing either level to be
+/// collapsed to a single operand.
+fn is_two_level(p: &ElrondPred, cnf: bool) -> bool {
+    let clause = |p: &ElrondPred| match (p, cnf) {
+        (ElrondPred::Or(p_vec), true) | (ElrondPred::And(p_vec), false) => {
+            p_vec.iter().all(is_literal)
+        }
+        (p, _) => is_literal(p),
+    };
+    match (p, cnf) {
+        (ElrondPred::And(p_vec), true) | (ElrondPred::Or(p_vec), false) => p_vec.iter().all(clause),
+        (p, _) => clause(p),
+    }
+}
+
+fn samples() -> Vec<ElrondPred> {
+    let (p, q, r) = (var("p"), var("q"), var("r"));
+    vec![
+        ElrondPred::And(vec![
+            ElrondPred::And(vec![p.clone()]),
+            ElrondPred::True,
+            ElrondPred::Or(vec![ElrondPred::Or(vec![q.clone(), r.clone()])]),
+            p.clone(),
+        ]),
+        ElrondPred::Ite(
+            Box::new(p.clone()),
+            Box::new(ElrondPred::Implies(
+                Box::new(q.clone()),
+                Box::new(r.clone()),
+            )),
+            Box::new(not(ElrondPred::Iff(
+                Box::new(q.clone()),
+                Box::new(r.clone()),
+            ))),
+        ),
+        not(ElrondPred::Or(vec![
+            ElrondPred::And(vec![p.clone(), not(q.clone())]),
+            ElrondPred::Iff(Box::new(r.clone()), Box::new(p.clone())),
+        ])),
+        ElrondPred::Implies(
+            Box::new(ElrondPred::Or(vec![p.clone(), q.clone()])),
+            Box::new(ElrondPred::And(vec![q.clone(), ElrondPred::Or(vec![r, p])])),
+        ),
+    ]
+}
+
+#[test]
+fn test_flatten_and_dedupe() {
+    let (p, q, r) = (var("p"), var("q"), var("r"));
+    let pred = ElrondPred::And(vec![
+        ElrondPred::And(vec![p.clone(), ElrondPred::And(vec![q.clone()])]),
+        ElrondPred::True,
+        ElrondPred::Or(vec![ElrondPred::Or(vec![r.clone()])]),
+        p.clone(),
+    ]);
+    assert_eq!(pred.simplify(), ElrondPred::And(vec![p, q, r]));
+}
+
+#[test]
+fn test_constant_folding() {
+    let p = var("p");
+    let cases = [
+        (ElrondPred::And(vec![]), ElrondPred::True),
+        (ElrondPred::Or(vec![]), falsity()),
+        (ElrondPred::And(vec![p.clone(), falsity()]), falsity()),
+        (
+            ElrondPred::Or(vec![p.clone(), not(not(ElrondPred::True))]),
+            ElrondPred::True,
+        ),
+        (ElrondPred::Or(vec![falsity(), p.clone()]), p.clone()),
+        (
+            ElrondPred::Implies(Box::new(ElrondPred::True), Box::new(p.clone())),
+            p.clone(),
+        ),
+        (
+            ElrondPred::Implies(Box::new(p.clone()), Box::new(falsity())),
+            not(p.clone()),
+        ),
+        (
+            ElrondPred::Iff(Box::new(falsity()), Box::new(p.clone())),
+            not(p.clone()),
+        ),
+        (
+            ElrondPred::Ite(
+                Box::new(p.clone()),
+                Box::new(ElrondPred::True),
+                Box::new(falsity()),
+            ),
+            p.clone(),
+        ),
+        (not(not(p.clone())), p),
+    ];
+    for (pred, expected) in cases {
+        assert_eq!(pred.simplify(), expected, "simplifying {pred}");
+    }
+}
+
+#[test]
+fn test_normal_forms_are_equivalent() {
+    for pred in samples() {
+        for form in FORMS {
+            let normalized = pred.normalize(form);
+            assert!(
+                equivalent(&pred, &normalized),
+                "{form} of {pred} is {normalized}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_normal_form_shapes() {
+    for pred in samples() {
+        assert!(is_ite_free(&pred.normalize(NormalForm::IteFree)));
+        assert!(is_nnf(&pred.normalize(NormalForm::Nnf)));
+        assert!(is_two_level(&pred.normalize(NormalForm::Cnf), true));
+        assert!(is_two_level(&pred.normalize(NormalForm::Dnf), false));
+    }
+}
+
+#[test]
+fn test_simplify_is_idempotent() {
+    for pred in samples() {
+        for form in FORMS {
+            let normalized = pred.normalize(form);
+            assert_eq!(normalized.normalize(form), normalized, "{form} of {pred}");
+        }
+    }
+}
+
+#[test]
+fn test_simplify_inferred_specs() {
+    let vc = vc();
+    let report = vc
+        .applied_specs()
+        .into_iter()
+        .filter_map(|name| Some((name, vc.spec(name)?)))
+        .map(|(name, spec)| {
+            let s = spec.1 .1.simplification(NormalForm::Simplified);
+            format!(
+                "{name}: size {} -> {}\n\t{}",
+                s.size_before, s.size_after, s.pred
+            )
+        })
+        .join("\n");
+    insta::assert_snapshot!(report);
+}
@@ -0,0 +1,143 @@
+mod common;
+
+use arwen_elrond_ipc::{
+    ipc::{
+        ElrondForallFormula, ElrondLiteral, ElrondPred, ElrondSimpleExpr, ElrondSpec,
+        ElrondTpedvar, ElrondType, FreeVar,
+    },
+    predicates::ElrondDatatype,
+    smt::{SmtError, SmtOptions},
+};
+use common::{assertion, vc, ASSERTION_WITH_PRE};
+
+fn axioms() -> SmtOptions {
+    SmtOptions {
+        list_axioms: true,
+        ..SmtOptions::default()
+    }
+}
+
+#[test]
+fn test_smt_assertion() {
+    insta::assert_snapshot!(assertion(ASSERTION_WITH_PRE)
+        .to_smt2(&SmtOptions::default())
+        .unwrap());
+}
+
+#[test]
+fn test_smt_assertion_axioms() {
+    insta::assert_snapshot!(assertion(ASSERTION_WITH_PRE).to_smt2(&axioms()).unwrap());
+}
+
+#[test]
+fn test_smt_inferred_spec() {
+    let vc = vc();
+    let push = vc.spec("Customstk.push").unwrap();
+    insta::assert_snapshot!(push.to_smt2(&axioms()).unwrap());
+}
+
+#[test]
+fn test_smt_forall_formula() {
+    let l = ElrondSimpleExpr::Var(ElrondType::IntList, "l".to_string());
+    let u = ElrondSimpleExpr::Var(ElrondType::Int, "u".to_string());
+    let formula = ElrondForallFormula(
+        vec![FreeVar(ElrondType::Int, "u".to_string())],
+        ElrondPred::Implies(
+            Box::new(ElrondPred::Atom(ElrondSimpleExpr::Op(
+                ElrondType::Bool,
+                "list_member".to_string(),
+                vec![l.clone(), u.clone()],
+            ))),
+            Box::new(ElrondPred::Atom(ElrondSimpleExpr::Op(
+                ElrondType::Bool,
+                "<=".to_string(),
+                vec![
+                    u,
+                    ElrondSimpleExpr::Literal(ElrondType::Int, ElrondLiteral::Int(-3)),
+                ],
+            ))),
+        ),
+    );
+    insta::assert_snapshot!(formula.to_smt2(&SmtOptions::default()).unwrap());
+}
+
+#[test]
+fn test_smt_tree_spec() {
+    let spec = assertion(
+        "let preds = [| \"mem\"; \"left\"; \"root\" |]
+
+let post (t : Splayhp.t) (x : int) (u : int) =
+  implies (root t x && mem t u) (left t x u || x == u || u > x)
+",
+    )
+    .post_spec;
+    let options = SmtOptions {
+        generic: ElrondDatatype::Tree,
+        ..SmtOptions::default()
+    };
+    insta::assert_snapshot!(spec.to_smt2(&options).unwrap());
+}
+
+#[test]
+fn test_smt_errors() {
+    let literal = ElrondSpec(
+        vec![ElrondTpedvar(ElrondType::IntList, "l".to_string())],
+        ElrondForallFormula(
+            vec![],
+            ElrondPred::Atom(ElrondSimpleExpr::Op(
+                ElrondType::Bool,
+                "==".to_string(),
+                vec![
+                    ElrondSimpleExpr::Var(ElrondType::IntList, "l".to_string()),
+                    ElrondSimpleExpr::Literal(ElrondType::IntList, ElrondLiteral::IntList(vec![1])),
+                ],
+            )),
+        ),
+    );
+    assert!(matches!(
+        literal.to_smt2(&SmtOptions::default()),
+        Err(SmtError::ListLiteral(_))
+    ));
+    assert!(literal
+        .to_smt2(&axioms())
+        .unwrap()
+        .contains("(= l (cons 1 nil))"));
+
+    let tree_pred_on_list = ElrondForallFormula(
+        vec![],
+        ElrondPred::Atom(ElrondSimpleExpr::Op(
+            ElrondType::Bool,
+            "root".to_string(),
+            vec![
+                ElrondSimpleExpr::Var(ElrondType::IntList, "l".to_string()),
+                ElrondSimpleExpr::Var(ElrondType::Int, "u".to_string()),
+            ],
+        )),
+    );
+    assert!(matches!(
+        tree_pred_on_list.to_smt2(&SmtOptions::default()),
+        Err(SmtError::IllTyped(_))
+    ));
+
+    let tuple = ElrondForallFormula(
+        vec![],
+        ElrondPred::Atom(ElrondSimpleExpr::Op(
+            ElrondType::Bool,
+            "==".to_string(),
+            vec![
+                ElrondSimpleExpr::Tuple(vec![
+                    ElrondSimpleExpr::Var(ElrondType::Int, "u".to_string()),
+                    ElrondSimpleExpr::Var(ElrondType::IntList, "l".to_string()),
+                ]),
+                ElrondSimpleExpr::Var(ElrondType::Int, "v".to_string()),
+            ],
+        )),
+    );
+    assert_eq!(
+        tuple.to_smt2(&SmtOptions::default()),
+        Err(SmtError::Unsupported(ElrondType::Tuple(vec![
+            ElrondType::Int,
+            ElrondType::IntList
+        ])))
+    );
+}
@@ -0,0 +1,201 @@
+use std::collections::{BTreeSet, HashMap};
+
+mod common;
+
+use arwen_elrond_ipc::{
+    assertion_parser::AssertionFileParser,
+    ipc::{
+        ElrondForallFormula, ElrondPred, ElrondSimpleExpr, ElrondSpec, ElrondTpedvar, ElrondType,
+        FreeVar,
+    },
+    subst::{fresh_name, SubstError, Substitution},
+};
+use common::vc;
+
+fn var(ty: ElrondType, name: &str) -> ElrondSimpleExpr {
+    ElrondSimpleExpr::Var(ty, name.to_string())
+}
+
+fn app(op: &str, args: Vec<ElrondSimpleExpr>) -> ElrondPred {
+    ElrondPred::Atom(ElrondSimpleExpr::Op(ElrondType::Bool, op.to_string(), args))
+}
+
+fn set(names: &[&str]) -> BTreeSet<String> {
+    names.iter().map(|n| n.to_string()).collect()
+}
+
+/// `l1 l2 ⊢ ∀ u_0. mem l1 u_0 ⇒ mem l2 u_0 ∨ u_0 == x`, where `x` is free.
+fn concat_post() -> ElrondSpec {
+    let l = |n| var(ElrondType::IntList, n);
+    let u = || var(ElrondType::Int, "u_0");
+    ElrondSpec(
+        vec![
+            ElrondTpedvar(ElrondType::IntList, "l1".to_string()),
+            ElrondTpedvar(ElrondType::IntList, "l2".to_string()),
+        ],
+        ElrondForallFormula(
+            vec![FreeVar(ElrondType::Int, "u_0".to_string())],
+            ElrondPred::Implies(
+                Box::new(app("mem", vec![l("l1"), u()])),
+                Box::new(ElrondPred::Or(vec![
+                    app("mem", vec![l("l2"), u()]),
+                    app("==", vec![u(), var(ElrondType::Int, "x")]),
+                ])),
+            ),
+        ),
+    )
+}
+
+#[test]
+fn test_free_and_bound_vars() {
+    let spec = concat_post();
+    assert_eq!(spec.bound_vars(), set(&["l1", "l2", "u_0"]));
+    assert_eq!(spec.free_vars(), set(&["x"]));
+    assert_eq!(spec.1.bound_vars(), set(&["u_0"]));
+    assert_eq!(spec.1.free_vars(), set(&["l1", "l2", "x"]));
+    assert_eq!(spec.1 .1.free_vars(), set(&["l1", "l2", "u_0", "x"]));
+}
+
+#[test]
+fn test_fresh_name() {
+    assert_eq!(fresh_name("u_0", &set(&["u_0", "u_1"])), "u_2");
+    assert_eq!(fresh_name("x", &set(&["x"])), "x_0");
+    assert_eq!(fresh_name("l_a", &set(&[])), "l_a_0");
+}
+
+#[test]
+fn test_instantiate() {
+    let actuals = [
+        var(ElrondType::IntList, "xs"),
+        var(ElrondType::IntList, "ys"),
+    ];
+    let formula = concat_post().instantiate(&actuals).unwrap();
+    assert_eq!(
+        formula.to_string(),
+        "forall u_0 : int . implies (mem xs u_0) (mem ys u_0 || u_0 == x)"
+    );
+    assert_eq!(formula.free_vars(), set(&["x", "xs", "ys"]));
+
+    assert_eq!(
+        concat_post().instantiate(&actuals[..1]),
+        Err(SubstError::Arity {
+            expected: 2,
+            found: 1
+        })
+    );
+}
+
+#[test]
+fn test_substitution_avoids_capture() {
+    // The engine's own `u_0` as an actual must not be captured by the quantifier
+    let actuals = [
+        var(ElrondType::IntList, "u_0"),
+        var(ElrondType::IntList, "l2"),
+    ];
+    let formula = concat_post().instantiate(&actuals).unwrap();
+    assert_eq!(
+        formula.to_string(),
+        "forall u_1 : int . implies (mem u_0 u_1) (mem l2 u_1 || u_1 == x)"
+    );
+    assert_eq!(formula.free_vars(), set(&["l2", "u_0", "x"]));
+
+    // Arguments are renamed too when substituting for a free variable of the spec
+    let subst: Substitution = [("x".to_string(), var(ElrondType::Int, "l1"))]
+        .into_iter()
+        .collect();
+    let spec = concat_post().substitute(&subst);
+    assert_eq!(
+        spec.to_string(),
+        "l1_0 : int list,l2 : int list ⊢ forall u_0 : int . implies (mem l1_0 u_0) (mem l2 u_0 || u_0 == l1)"
+    );
+    assert_eq!(spec.free_vars(), set(&["l1"]));
+
+    // Bound variables shadow the substitution
+    let subst: Substitution = [("u_0".to_string(), var(ElrondType::Int, "y"))]
+        .into_iter()
+        .collect();
+    assert_eq!(concat_post().substitute(&subst), concat_post());
+}
+
+#[test]
+fn test_alpha_rename() {
+    let spec = concat_post();
+    let renamed = spec.1.alpha_rename(&[("u_0", "u")]).unwrap();
+    assert_eq!(
+        renamed.to_string(),
+        "forall u : int . implies (mem l1 u) (mem l2 u || u == x)"
+    );
+    assert_eq!(renamed.free_vars(), spec.1.free_vars());
+
+    let swapped = spec.alpha_rename(&[("l1", "l2"), ("l2", "l1")]).unwrap();
+    assert_eq!(
+        swapped.1 .1.to_string(),
+        "implies (mem l2 u_0) (mem l1 u_0 || u_0 == x)"
+    );
+
+    assert_eq!(
+        spec.1.alpha_rename(&[("u_0", "x")]),
+        Err(SubstError::Capture {
+            from: "u_0".to_string(),
+            to: "x".to_string()
+        })
+    );
+    assert_eq!(
+        spec.alpha_rename(&[("u_0", "l1")]),
+        Err(SubstError::Capture {
+            from: "u_0".to_string(),
+            to: "l1".to_string()
+        })
+    );
+    assert_eq!(
+        spec.1.alpha_rename(&[("l1", "xs")]),
+        Err(SubstError::NotBound("l1".to_string()))
+    );
+}
+
+#[test]
+fn test_alpha_rename_engine_names() {
+    let vc = vc();
+    let push = vc.spec("Customstk.push").unwrap();
+    let bound = push.bound_vars();
+    assert!(bound.contains("u_0"), "{bound:?}");
+    let renamed = push.alpha_rename(&[("u_0", "elem")]).unwrap();
+    let bound = renamed.bound_vars();
+    assert!(bound.contains("elem") && !bound.contains("u_0"));
+    assert_eq!(renamed.free_vars(), push.free_vars());
+    assert_eq!(renamed.alpha_rename(&[("elem", "u_0")]).unwrap(), *push);
+}
+
+#[test]
+fn test_assertion_vars() {
+    let file = AssertionFileParser::new()
+        .parse(
+            "let preds = [| \"mem\" |]
+let post (l1 : Customstk.t) (u : int) =
+  implies (mem l1 u) (u == v)
+",
+        )
+        .unwrap();
+    let spec = file.post_spec;
+    assert_eq!(spec.1.free_vars(), set(&["l1", "u", "v"]));
+    assert_eq!(spec.bound_vars(), set(&["l1", "u"]));
+    assert_eq!(spec.free_vars(), set(&["v"]));
+
+    let instance = spec.instantiate(&["xs", "v"]).unwrap();
+    assert_eq!(instance.to_string(), "implies (mem xs v) (v == v)");
+    let names = HashMap::from([
+        ("u".to_string(), "v".to_string()),
+        ("v".to_string(), "u".to_string()),
+    ]);
+    assert_eq!(
+        spec.1.rename(&names).to_string(),
+        "implies (mem l1 v) (v == u)"
+    );
+    assert_eq!(
+        spec.instantiate(&["xs"]),
+        Err(SubstError::Arity {
+            expected: 2,
+            found: 1
+        })
+    );
+}
@@ -0,0 +1,121 @@
+use arwen_elrond_ipc::ipc::{
+    ElrondKnownPredicate::{self, *},
+    ElrondTree::{self, Leaf},
+    ElrondValue,
+};
+
+fn node(x: i64, l: ElrondTree, r: ElrondTree) -> ElrondTree {
+    ElrondTree::node(x, l, r)
+}
+
+fn leaf(x: i64) -> ElrondTree {
+    ElrondTree::singleton(x)
+}
+
+/// Shapes produced by the splay heap benchmark's `insert`, `partition` and `merge`.
+fn splay_shapes() -> Vec<(&'static str, ElrondTree)> {
+    vec![
+        ("empty", Leaf),
+        ("singleton", leaf(1)),
+        // inserting 3, 2, 1 in turn splays each new minimum to the root
+        ("right spine", node(1, Leaf, node(2, Leaf, leaf(3)))),
+        // inserting 1, 2, 3 in turn keeps the maximum at the root
+        ("left spine", node(3, node(2, leaf(1), Leaf), Leaf)),
+        ("balanced", node(2, leaf(1), leaf(3))),
+        // an inner node with subtrees on both sides
+        ("zig-zag", node(4, node(2, leaf(1), leaf(3)), leaf(5))),
+        ("duplicates", node(2, leaf(2), leaf(3))),
+    ]
+}
+
+fn apply(p: ElrondKnownPredicate, t: &ElrondTree, ints: &[i64]) -> bool {
+    let args = std::iter::once(ElrondValue::T(t.clone()))
+        .chain(ints.iter().map(|i| ElrondValue::I(*i)))
+        .collect::<Vec<_>>();
+    p.apply(&args).unwrap()
+}
+
+#[test]
+fn test_tree_predicates() {
+    #[rustfmt::skip]
+    let cases: &[(&str, ElrondKnownPredicate, &[i64], bool)] = &[
+        ("empty", member, &[1], false),
+        ("empty", root, &[1], false),
+        ("empty", ance, &[1, 1], false),
+        ("singleton", member, &[1], true),
+        ("singleton", root, &[1], true),
+        ("singleton", once, &[1], true),
+        ("singleton", left, &[1, 1], false),
+        ("singleton", ance, &[1, 1], false),
+        ("right spine", root, &[1], true),
+        ("right spine", right, &[1, 3], true),
+        ("right spine", right, &[2, 3], true),
+        ("right spine", left, &[3, 1], false),
+        ("right spine", ance, &[1, 3], true),
+        ("right spine", ance, &[3, 1], false),
+        ("right spine", para, &[1, 3], false),
+        ("left spine", root, &[3], true),
+        ("left spine", left, &[3, 1], true),
+        ("left spine", right, &[1, 3], false),
+        ("left spine", ance, &[2, 1], true),
+        ("balanced", para, &[1, 3], true),
+        ("balanced", para, &[3, 1], false),
+        ("balanced", left, &[2, 1], true),
+        ("balanced", right, &[2, 3], true),
+        ("balanced", ance, &[1, 3], false),
+        ("zig-zag", left, &[4, 3], true),
+        ("zig-zag", right, &[2, 3], true),
+        ("zig-zag", para, &[1, 3], true),
+        ("zig-zag", para, &[3, 5], true),
+        ("zig-zag", para, &[5, 3], false),
+        ("zig-zag", ance, &[4, 1], true),
+        ("zig-zag", ance, &[2, 5], false),
+        ("zig-zag", root, &[2], false),
+        ("duplicates", once, &[2], false),
+        ("duplicates", once, &[3], true),
+        ("duplicates", left, &[2, 2], true),
+        ("duplicates", ance, &[2, 2], true),
+    ];
+    let shapes = splay_shapes();
+    for (shape, p, ints, expected) in cases {
+        let (_, t) = shapes.iter().find(|(name, _)| name == shape).unwrap();
+        assert_eq!(apply(*p, t, ints), *expected, "{p} ({shape}: {t}) {ints:?}");
+    }
+}
+
+#[test]
+fn test_tree_labels() {
+    let expected: &[(&str, &[i64])] = &[
+        ("empty", &[]),
+        ("singleton", &[1]),
+        ("right spine", &[1, 2, 3]),
+        ("left spine", &[3, 2, 1]),
+        ("balanced", &[2, 1, 3]),
+        ("zig-zag", &[4, 2, 1, 3, 5]),
+        ("duplicates", &[2, 2, 3]),
+    ];
+    for ((shape, t), (name, labels)) in splay_shapes().iter().zip(expected) {
+        assert_eq!(shape, name);
+        assert_eq!(t.labels(), *labels, "{shape}");
+    }
+}
+
+#[test]
+fn test_list_only_predicates_reject_trees() {
+    for p in [head, length, order, sorted] {
+        let args = [ElrondValue::T(leaf(1)), ElrondValue::I(1)];
+        assert!(p.apply(&args).is_err(), "{p}");
+    }
+}
+
+#[test]
+fn test_tree_wire_format() {
+    let t = ElrondValue::T(node(2, leaf(1), Leaf));
+    let json = serde_json::to_string(&t).unwrap();
+    assert_eq!(
+        json,
+        r#"{"T":{"Node":[2,{"Node":[1,"Leaf","Leaf"]},"Leaf"]}}"#
+    );
+    assert_eq!(serde_json::from_str::<ElrondValue>(&json).unwrap(), t);
+    assert_eq!(t.to_string(), "Node(2, Node(1, Leaf, Leaf), Leaf)");
+}
@@ -0,0 +1,211 @@
+use arwen_elrond_ipc::{
+    assertion_parser::AssertionFileParser,
+    ipc::{ElrondAssertion, ElrondSimpleExpr, ElrondTpedvar, ElrondType},
+    lowering::AbstractTypes,
+    predicates::ElrondDatatype,
+    program_parser::{AssertionParser, SpannedAssertionParser},
+    typecheck::{parse_typed, parse_typed_with, TypeErrorKind, UNKNOWN_TYPE},
+    visit::{walk_expr, Visitor},
+};
+use itertools::Itertools;
+
+const WELL_TYPED: &str = "let preds = [| \"mem\"; \"hd\"; \"once\" |]
+let pre (l1 : Customstk.t) (u : int) =
+  once l1 u
+let post (l1 : Customstk.t) (l2 : Customstk.t) (u : int) (v : int) =
+  iff (mem l2 u) mem l1 u || u == v && implies (hd l2 u) (u >= v)
+";
+
+/// Every type in an expression, so tests can check none is left unknown.
+#[derive(Default)]
+struct Types(Vec<ElrondType>);
+
+impl Visitor for Types {
+    fn visit_expr(&mut self, e: &ElrondSimpleExpr) {
+        if let ElrondSimpleExpr::Var(ty, _) | ElrondSimpleExpr::Op(ty, _, _) = e {
+            self.0.push(ty.clone());
+        }
+        walk_expr(self, e)
+    }
+}
+
+fn types(assertion: &ElrondAssertion) -> Vec<ElrondType> {
+    let mut types = Types::default();
+    for spec in assertion.pre_spec.iter().chain([&assertion.post_spec]) {
+        types.visit_pred(&spec.1 .1);
+    }
+    types.0
+}
+
+/// The errors in `source`, each prefixed with its line and column.
+fn errors(source: &str) -> String {
+    parse_typed(source)
+        .unwrap_err()
+        .iter()
+        .map(|e| e.display_in(source))
+        .join("\n")
+}
+
+#[test]
+fn test_typecheck_well_typed() {
+    let assertion = parse_typed(WELL_TYPED).unwrap();
+    let types = types(&assertion);
+    assert!(!types.is_empty());
+    assert!(types
+        .iter()
+        .all(|ty| !matches!(ty, ElrondType::Generic(s) if s == UNKNOWN_TYPE)));
+    // `Customstk.t` is resolved to the lists `hd` applies to, in the arguments too
+    let arg_types = assertion
+        .pre_spec
+        .iter()
+        .chain([&assertion.post_spec])
+        .flat_map(|spec| spec.0.iter().map(|ElrondTpedvar(ty, _)| ty));
+    assert!(types
+        .iter()
+        .chain(arg_types)
+        .all(|ty| !matches!(ty, ElrondType::Generic(_))));
+    insta::assert_snapshot!(assertion.post_spec.to_string());
+}
+
+#[test]
+fn test_typecheck_abstract_types() {
+    // `mem` applies to lists and trees alike
+    let source = "let preds = [| \"mem\" |]
+let post (t : Tree.t) (u : int) =
+  mem t u
+";
+    insta::assert_snapshot!(errors(source), @"Tree.t could be a list or a tree; name it in the abstract types");
+
+    let types = AbstractTypes(vec![("Tree.t".to_string(), ElrondDatatype::Tree)]);
+    let assertion = parse_typed_with(source, &types).unwrap();
+    assert_eq!(
+        assertion.post_spec.0[0],
+        ElrondTpedvar(ElrondType::IntTree, "t".to_string())
+    );
+
+    // No datatype is both `hd`'s list and `left`'s tree
+    let source = "let preds = [| \"hd\"; \"left\" |]
+let post (s : Customstk.t) (u : int) =
+  hd s u && left s u u
+";
+    insta::assert_snapshot!(errors(source), @"no datatype fits every use of Customstk.t");
+
+    // Without a predicate on its values, an abstract type is a list
+    let source = "let preds = [| |]
+let post (a : Customstk.t) (b : Customstk.t) =
+  a == b
+";
+    let assertion = parse_typed(source).unwrap();
+    assert_eq!(
+        assertion.post_spec.0[0],
+        ElrondTpedvar(ElrondType::IntList, "a".to_string())
+    );
+    assert_eq!(
+        assertion.types.get("Customstk.t"),
+        Some(ElrondDatatype::List)
+    );
+}
+
+#[test]
+fn test_typecheck_predicate_on_int() {
+    let source = "let preds = [| \"mem\" |]
+let post (l : Customstk.t) (u : int) =
+  mem u l
+";
+    insta::assert_snapshot!(errors(source), @r"
+    3:3: mem expects list or tree, found int
+    3:3: mem expects int, found Customstk.t
+    ");
+}
+
+#[test]
+fn test_typecheck_compare_lists() {
+    let source = "let preds = [| \"mem\" |]
+let post (l1 : Customstk.t) (l2 : Customstk.t) (u : int) =
+  mem l1 u && l1 <= l2
+";
+    insta::assert_snapshot!(errors(source), @r"
+    3:18: <= expects int, found Customstk.t
+    3:18: <= expects int, found Customstk.t
+    ");
+}
+
+#[test]
+fn test_typecheck_tree_predicate_on_bool() {
+    let source = "let preds = [| \"left\" |]
+let post (b : bool) (u : int) (v : int) =
+  left b u v
+";
+    let errs = parse_typed(source).unwrap_err();
+    assert_eq!(
+        errs[0].kind,
+        TypeErrorKind::Mismatch {
+            op: Some("left".to_string()),
+            expected: "tree".to_string(),
+            found: ElrondType::Bool,
+        },
+        "{}",
+        errs[0]
+    );
+}
+
+#[test]
+fn test_typecheck_other_errors() {
+    let source = "let preds = [| \"mem\"; \"hd\" |]
+let post (l : Customstk.t) (u : int) =
+  mem l w && hd l && frob l u && u == l && u
+";
+    insta::assert_snapshot!(errors(source), @r"
+    3:9: unbound variable w
+    3:14: hd expects 2 arguments, found 1
+    3:22: unknown predicate or operator frob
+    3:36: == expects int, found Customstk.t
+    3:44: an atom must be bool, found int
+    ");
+}
+
+#[test]
+fn test_typecheck_spans_follow_nesting() {
+    let source = "let preds = [| \"mem\"; \"hd\" |]
+let pre (l : Customstk.t) (u : int) =
+  hd u l
+let post (l : Customstk.t) (u : int) (v : int) =
+  iff (mem l u) implies (u == l) (mem l w || v)
+";
+    insta::assert_snapshot!(errors(source), @r"
+    3:3: hd expects list, found int
+    3:3: hd expects int, found Customstk.t
+    5:28: == expects int, found Customstk.t
+    5:41: unbound variable w
+    5:46: an atom must be bool, found int
+    ");
+
+    let (assertion, _) = SpannedAssertionParser::new().parse(source).unwrap();
+    assert_eq!(AssertionParser::new().parse(source), Ok(assertion));
+}
+
+#[test]
+fn test_typecheck_syntax_error() {
+    let source = "let preds = [| \"mem\" |]
+let post (l : Customstk.t) =
+  mem l &&
+";
+    let errs = parse_typed(source).unwrap_err();
+    assert_eq!(errs.len(), 1);
+    assert!(matches!(errs[0].kind, TypeErrorKind::Syntax(_)));
+    assert_eq!(errs[0].span.unwrap().line_col(source).0, 3);
+}
+
+#[test]
+fn test_typecheck_lowered_assertion() {
+    let file = AssertionFileParser::new()
+        .parse(
+            "let preds = [| \"mem\"; \"hd\" |]
+let post (l1 : Customstk.t) (l2 : Customstk.t) (u : int) =
+  implies (hd l1 u) (mem l2 u)
+",
+        )
+        .unwrap();
+    let assertion = ElrondAssertion::try_from(&file).unwrap();
+    assert_eq!(assertion.typecheck().unwrap(), assertion);
+}
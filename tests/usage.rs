@@ -0,0 +1,111 @@
+mod common;
+
+use arwen_elrond_ipc::{
+    ipc::{
+        ElrondAssertion, ElrondKnownPredicate, ElrondPred, ElrondPredicates, ElrondResult,
+        ElrondSimpleExpr, ElrondType,
+    },
+    usage::PredicateIssue,
+};
+use common::{parse, vc};
+use itertools::Itertools;
+
+fn issues(issues: &[PredicateIssue]) -> String {
+    issues.iter().join("\n")
+}
+
+#[test]
+fn test_declared_predicates_only() {
+    let file = parse(
+        "let preds = [| \"mem\"; \"hd\" |]
+let post (l1 : Customstk.t) (l2 : Customstk.t) (u : int) =
+  iff (mem l2 u) (mem l1 u) && implies (hd l2 u) (u == u)
+",
+    );
+    assert_eq!(file.check_predicates(&[]), vec![]);
+    let assertion = ElrondAssertion::try_from(&file).unwrap();
+    assert_eq!(assertion.check_predicates(&[]), vec![]);
+}
+
+#[test]
+fn test_undeclared_and_unused() {
+    let file = parse(
+        "let preds = [| \"mem\"; \"sorted\"; \"mem\" |]
+let pre (l1 : Customstk.t) (u : int) =
+  hd l1 u
+let post (l1 : Customstk.t) (u : int) (v : int) =
+  mem l1 u && (ord l1 u v || ord l1 v u)
+",
+    );
+    let expected = vec![
+        PredicateIssue::Undeclared {
+            spec: "pre".to_string(),
+            predicate: ElrondKnownPredicate::head,
+            count: 1,
+        },
+        PredicateIssue::Undeclared {
+            spec: "post".to_string(),
+            predicate: ElrondKnownPredicate::order,
+            count: 2,
+        },
+        PredicateIssue::Unused(ElrondKnownPredicate::sorted),
+    ];
+    assert_eq!(file.check_predicates(&[]), expected);
+    insta::assert_snapshot!(issues(&expected), @r"
+    pre applies hd once, but it is not declared
+    post applies ord 2 times, but it is not declared
+    sorted is declared but never used
+    ");
+
+    // The lowered assertion applies the same predicates
+    let assertion = ElrondAssertion::try_from(&file).unwrap();
+    assert_eq!(assertion.check_predicates(&[]), expected);
+
+    // Predicates that are merely allowed are not reported when unused
+    assert_eq!(
+        file.check_predicates(&[ElrondKnownPredicate::head, ElrondKnownPredicate::root]),
+        expected[1..]
+    );
+}
+
+#[test]
+fn test_result_specs() {
+    let vc = vc();
+    let result = ElrondResult::Result(vc.spectable);
+    let declared = ElrondPredicates(vec![
+        ElrondKnownPredicate::member,
+        ElrondKnownPredicate::head,
+    ]);
+    assert_eq!(result.check_predicates(&declared), vec![]);
+
+    let declared = ElrondPredicates(vec![
+        ElrondKnownPredicate::member,
+        ElrondKnownPredicate::once,
+    ]);
+    insta::assert_snapshot!(issues(&result.check_predicates(&declared)));
+
+    assert_eq!(
+        ElrondResult::Cex(vec![]).check_predicates(&declared),
+        vec![]
+    );
+}
+
+#[test]
+fn test_unknown_predicate() {
+    let vc = vc();
+    let mut spec = vc.spec("equal").unwrap().clone();
+    spec.1 .1 = ElrondPred::Atom(ElrondSimpleExpr::Op(
+        ElrondType::Bool,
+        "not_a_pred".to_string(),
+        vec![],
+    ));
+    let result = ElrondResult::Result(vec![(spec, "equal".to_string())]);
+    assert_eq!(
+        result.check_predicates(&ElrondPredicates(vec![])),
+        vec![PredicateIssue::Unknown {
+            spec: "equal".to_string(),
+            name: "not_a_pred".to_string(),
+            count: 1
+        }]
+    );
+}
@@ -0,0 +1,33 @@
+mod common;
+
+use arwen_elrond_ipc::vc::Vc;
+use common::vc;
+
+#[test]
+fn test_customstk_vc_holes() {
+    let vc = vc();
+
+    assert_eq!(
+        vc.holes(),
+        vec![
+            "Customstk.top",
+            "Customstk.tail",
+            "Customstk.push",
+            "Customstk.is_empty"
+        ]
+    );
+    assert_eq!(vc.multi_pre.len(), 2);
+    assert!(vc.spec("concat_post").is_some());
+}
+
+#[test]
+fn test_customstk_vc_display() {
+    let vc = vc();
+
+    insta::assert_snapshot!(vc);
+}
+
+#[test]
+fn test_vc_bad_json() {
+    assert!("{\"vc\": {}}".parse::<Vc>().is_err());
+}
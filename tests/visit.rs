@@ -0,0 +1,145 @@
+use std::collections::BTreeSet;
+
+mod common;
+
+use arwen_elrond_ipc::{
+    assertion_parser::AssertionParser,
+    ipc::{ElrondPred, ElrondSimpleExpr, ElrondType},
+    ipc_assertion::Assertion,
+    visit::{walk_expr, walk_expr_mut, walk_pred_fold, Fold, Visitor, VisitorMut},
+};
+use common::vc;
+
+fn push_body() -> ElrondPred {
+    let vc = vc();
+    vc.spec("Customstk.push").unwrap().1 .1.clone()
+}
+
+fn assertion() -> Assertion {
+    AssertionParser::new()
+        .parse("iff (mem l3 u) (mem l1 u || mem l2 u) && implies (hd l3 u) (u == v)")
+        .unwrap()
+}
+
+/// Variables in order of appearance, with repeats.
+#[derive(Default)]
+struct Vars(Vec<String>);
+
+impl Visitor for Vars {
+    fn visit_expr(&mut self, e: &ElrondSimpleExpr) {
+        match e {
+            ElrondSimpleExpr::Var(_, name) => self.0.push(name.clone()),
+            e => walk_expr(self, e),
+        }
+    }
+
+    fn visit_name(&mut self, name: &str) {
+        self.0.push(name.to_string())
+    }
+}
+
+struct Rename<'a>(&'a str, &'a str);
+
+impl VisitorMut for Rename<'_> {
+    fn visit_expr_mut(&mut self, e: &mut ElrondSimpleExpr) {
+        match e {
+            ElrondSimpleExpr::Var(_, name) if name == self.0 => *name = self.1.to_string(),
+            e => walk_expr_mut(self, e),
+        }
+    }
+
+    fn visit_name_mut(&mut self, name: &mut String) {
+        if name == self.0 {
+            *name = self.1.to_string()
+        }
+    }
+}
+
+/// Rewrites `implies a b` to `!a || b`.
+struct NoImplies;
+
+impl Fold for NoImplies {
+    fn fold_pred(&mut self, p: ElrondPred) -> ElrondPred {
+        match walk_pred_fold(self, p) {
+            ElrondPred::Implies(p1, p2) => ElrondPred::Or(vec![ElrondPred::Not(p1), *p2]),
+            p => p,
+        }
+    }
+}
+
+struct Identity;
+
+impl Fold for Identity {}
+
+impl VisitorMut for Identity {}
+
+#[test]
+fn test_visitor_collects_vars() {
+    let mut vars = Vars::default();
+    vars.visit_pred(&push_body());
+    let distinct: BTreeSet<_> = vars.0.iter().map(String::as_str).collect();
+    assert_eq!(distinct, BTreeSet::from(["i_0", "il_0", "il_1", "u_0"]));
+
+    let mut names = Vars::default();
+    names.visit_assertion(&assertion());
+    assert_eq!(
+        names.0,
+        ["l3", "u", "l1", "u", "l2", "u", "l3", "u", "u", "v"]
+    );
+}
+
+#[test]
+fn test_visitor_mut_renames() {
+    let mut pred = push_body();
+    Rename("u_0", "x").visit_pred_mut(&mut pred);
+    let mut vars = Vars::default();
+    vars.visit_pred(&pred);
+    assert!(!vars.0.contains(&"u_0".to_string()));
+    assert!(vars.0.contains(&"x".to_string()));
+
+    let mut a = assertion();
+    Rename("u", "w").visit_assertion_mut(&mut a);
+    assert_eq!(
+        a.to_string(),
+        "iff (mem l3 w) (mem l1 w || mem l2 w) && implies (hd l3 w) (w == v)"
+    );
+}
+
+#[test]
+fn test_fold_rewrites_single_case() {
+    let a = ElrondPred::Atom(ElrondSimpleExpr::Var(ElrondType::Bool, "a".to_string()));
+    let b = ElrondPred::Atom(ElrondSimpleExpr::Var(ElrondType::Bool, "b".to_string()));
+    let pred = ElrondPred::And(vec![
+        a.clone(),
+        ElrondPred::Implies(
+            Box::new(a.clone()),
+            Box::new(ElrondPred::Implies(
+                Box::new(b.clone()),
+                Box::new(a.clone()),
+            )),
+        ),
+    ]);
+    let expected = ElrondPred::And(vec![
+        a.clone(),
+        ElrondPred::Or(vec![
+            ElrondPred::Not(Box::new(a.clone())),
+            ElrondPred::Or(vec![ElrondPred::Not(Box::new(b)), a]),
+        ]),
+    ]);
+    assert_eq!(NoImplies.fold_pred(pred), expected);
+}
+
+#[test]
+fn test_default_traversals_are_identity() {
+    let pred = push_body();
+    assert_eq!(Identity.fold_pred(pred.clone()), pred);
+    let mut copy = pred.clone();
+    Identity.visit_pred_mut(&mut copy);
+    assert_eq!(copy, pred);
+
+    let a = assertion();
+    assert_eq!(Identity.fold_assertion(a.clone()), a);
+    let mut copy = a.clone();
+    Identity.visit_assertion_mut(&mut copy);
+    assert_eq!(copy, a);
+}